@@ -0,0 +1,68 @@
+use accesskit::{Node, NodeId, Role};
+use bevy::a11y::AccessibilityNode;
+use bevy::prelude::*;
+
+use crate::label::WidgetLabels;
+
+/// Brings `entity`'s `AccessibilityNode` in line with the widget state, or
+/// inserts one if it has none yet.
+///
+/// The node is only written when `role` or `update` actually change it. Widget
+/// systems rerun whenever the node changes, so this also puts the role back
+/// after bevy_ui's button handling resets it to `Role::Button`, while keeping
+/// the label bevy_ui derives from the button's text.
+pub(crate) fn sync_accessibility_node(
+    commands: &mut Commands,
+    entity: Entity,
+    node: Option<Mut<AccessibilityNode>>,
+    role: Role,
+    update: impl FnOnce(&mut Node),
+) {
+    match node {
+        Some(mut node) => {
+            let mut next = node.0.clone();
+            next.set_role(role);
+            update(&mut next);
+            if next != node.0 {
+                node.0 = next;
+            }
+        }
+        None => {
+            let mut next = Node::new(role);
+            update(&mut next);
+            commands.entity(entity).insert(AccessibilityNode(next));
+        }
+    }
+}
+
+/// Whether a part whose state lives on another entity needs syncing: the
+/// state changed, or the node is new or was changed by someone else.
+pub(crate) fn needs_accessibility_sync(state_changed: bool, node: &Option<Mut<AccessibilityNode>>) -> bool {
+    state_changed || node.as_ref().is_none_or(|node| node.is_changed())
+}
+
+/// Sets or clears the disabled flag.
+pub(crate) fn set_disabled(node: &mut Node, disabled: bool) {
+    if disabled {
+        node.set_disabled();
+    } else {
+        node.clear_disabled();
+    }
+}
+
+/// The AccessKit id bevy gives `entity`'s node.
+pub(crate) fn node_id(entity: Entity) -> NodeId {
+    NodeId(entity.to_bits())
+}
+
+/// Points the control's node at its label and description text nodes.
+pub(crate) fn set_label_links(node: &mut Node, labels: Option<&WidgetLabels>) {
+    match labels.and_then(|labels| labels.label) {
+        Some(label) => node.set_labelled_by(vec![node_id(label)]),
+        None => node.clear_labelled_by(),
+    }
+    match labels.and_then(|labels| labels.description) {
+        Some(description) => node.set_described_by(vec![node_id(description)]),
+        None => node.clear_described_by(),
+    }
+}
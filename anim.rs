@@ -0,0 +1,317 @@
+use bevy::math::NormedVectorSpace;
+use bevy::prelude::*;
+
+use crate::motion::WidgetMotionSettings;
+
+/// Shape of a tween over its duration.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum EasingFunction {
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    Bounce,
+    Elastic,
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`. The x coordinates are clamped to
+    /// `0..=1`; the y coordinates may overshoot.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl EasingFunction {
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            EasingFunction::Linear => t,
+            EasingFunction::EaseIn => t * t,
+            EasingFunction::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            EasingFunction::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            EasingFunction::Bounce => {
+                if t < 1.0 / 2.75 {
+                    7.5625 * t * t
+                } else if t < 2.0 / 2.75 {
+                    let t = t - 1.5 / 2.75;
+                    7.5625 * t * t + 0.75
+                } else if t < 2.5 / 2.75 {
+                    let t = t - 2.25 / 2.75;
+                    7.5625 * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / 2.75;
+                    7.5625 * t * t + 0.984375
+                }
+            }
+            EasingFunction::Elastic => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    let c4 = (2.0 * std::f32::consts::PI) / 3.0;
+                    let result = -2.0f32.powf(10.0 * t - 10.0) * ((t * 10.0 - 10.75) * c4).sin();
+                    result.clamp(0.0, 1.0) // Prevent negative overshoot
+                }
+            }
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => cubic_bezier(t, *x1, *y1, *x2, *y2),
+        }
+    }
+}
+
+/// One axis of a cubic Bézier running from 0 to 1 through `p1` and `p2`.
+fn bezier_axis(s: f32, p1: f32, p2: f32) -> f32 {
+    let u = 1.0 - s;
+    3.0 * u * u * s * p1 + 3.0 * u * s * s * p2 + s * s * s
+}
+
+fn bezier_axis_slope(s: f32, p1: f32, p2: f32) -> f32 {
+    let u = 1.0 - s;
+    3.0 * u * u * p1 + 6.0 * u * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+}
+
+fn cubic_bezier(t: f32, x1: f32, y1: f32, x2: f32, y2: f32) -> f32 {
+    const EPSILON: f32 = 1e-6;
+    let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+
+    // Find the curve parameter whose x is `t`. Newton's method converges in a
+    // few steps unless the curve is flat there, then bisection takes over.
+    let mut s = t;
+    for _ in 0..8 {
+        let error = bezier_axis(s, x1, x2) - t;
+        if error.abs() < EPSILON {
+            return bezier_axis(s, y1, y2);
+        }
+        let slope = bezier_axis_slope(s, x1, x2);
+        if slope.abs() < EPSILON {
+            break;
+        }
+        s = (s - error / slope).clamp(0.0, 1.0);
+    }
+
+    let (mut low, mut high) = (0.0, 1.0);
+    s = t;
+    for _ in 0..32 {
+        let x = bezier_axis(s, x1, x2);
+        if (x - t).abs() < EPSILON {
+            break;
+        }
+        if x < t {
+            low = s;
+        } else {
+            high = s;
+        }
+        s = (low + high) / 2.0;
+    }
+    bezier_axis(s, y1, y2)
+}
+
+/// Moves from start to target over a fixed time along an easing curve.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct Tween {
+    /// Seconds
+    pub duration: f32,
+    pub easing: EasingFunction,
+}
+
+impl Tween {
+    pub fn new(duration: f32, easing: EasingFunction) -> Self {
+        Self { duration, easing }
+    }
+}
+
+impl Default for Tween {
+    fn default() -> Self {
+        Self::new(0.2, EasingFunction::EaseOut)
+    }
+}
+
+/// Pulls the value towards its target like a damped spring. Unlike a tween
+/// it keeps its velocity when the target changes mid-flight.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct Spring {
+    pub stiffness: f32,
+    pub damping: f32,
+    /// Distance and speed below which the spring counts as at rest
+    pub precision: f32,
+}
+
+impl Spring {
+    pub fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            precision: 0.01,
+        }
+    }
+
+    /// A spring that never overshoots and is within 1% of its target after
+    /// about `settle_time` seconds.
+    pub fn critically_damped(settle_time: f32) -> Self {
+        // (1 + ωt)e^(-ωt), the distance left, drops below 1% at ωt ≈ 6.64
+        let omega = 6.64 / settle_time.max(0.001);
+        Self::new(omega * omega, 2.0 * omega)
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::new(170.0, 26.0)
+    }
+}
+
+/// How an animated property reaches a new value.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum Transition {
+    Tween(Tween),
+    Spring(Spring),
+}
+
+impl Transition {
+    /// Shorthand for `Tween::new(duration, easing).into()`.
+    pub fn tween(duration: f32, easing: EasingFunction) -> Self {
+        Transition::Tween(Tween::new(duration, easing))
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Transition::Tween(Tween::default())
+    }
+}
+
+impl From<Tween> for Transition {
+    fn from(tween: Tween) -> Self {
+        Transition::Tween(tween)
+    }
+}
+
+impl From<Spring> for Transition {
+    fn from(spring: Spring) -> Self {
+        Transition::Spring(spring)
+    }
+}
+
+/// Longest step the spring integrator takes, so results do not depend on the
+/// frame rate.
+const SPRING_SUBSTEP: f32 = 1.0 / 240.0;
+/// Frame time the spring integrates at most, so a hitch cannot stall a frame
+/// with thousands of substeps.
+const SPRING_MAX_FRAME: f32 = 0.25;
+
+/// A property animated towards a target with a `Transition`.
+///
+/// Set the target when the widget state changes and call `update` once per
+/// frame. `f32`, `Vec2`, `Vec3` and `Vec4` all work; animate colors as
+/// `LinearRgba::to_vec4`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimatedValue<T> {
+    value: T,
+    velocity: T,
+    start: T,
+    target: T,
+    elapsed: f32,
+    settled: bool,
+}
+
+impl<T: NormedVectorSpace + PartialEq> Default for AnimatedValue<T> {
+    fn default() -> Self {
+        Self::new(T::ZERO)
+    }
+}
+
+impl<T: NormedVectorSpace + PartialEq> AnimatedValue<T> {
+    /// At rest at `value`.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            velocity: T::ZERO,
+            start: value,
+            target: value,
+            elapsed: 0.0,
+            settled: true,
+        }
+    }
+
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    pub fn target(&self) -> T {
+        self.target
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.settled
+    }
+
+    /// Starts moving towards `target` from the current value. Setting the
+    /// target it already has does nothing.
+    pub fn set_target(&mut self, target: T) {
+        if target == self.target {
+            return;
+        }
+        self.start = self.value;
+        self.target = target;
+        self.elapsed = 0.0;
+        self.settled = false;
+    }
+
+    /// Moves to `value` at once and stops there.
+    pub fn jump_to(&mut self, value: T) {
+        *self = Self::new(value);
+    }
+
+    /// Advances the animation by `dt` seconds. Returns true on the step that
+    /// reaches the target.
+    pub fn step(&mut self, transition: &Transition, dt: f32) -> bool {
+        if self.settled {
+            return false;
+        }
+
+        match transition {
+            Transition::Tween(tween) => {
+                self.elapsed += dt;
+                let t = if tween.duration > 0.0 { self.elapsed / tween.duration } else { 1.0 };
+                if t >= 1.0 {
+                    return self.finish();
+                }
+                self.value = self.start.lerp(self.target, tween.easing.sample(t));
+            }
+            Transition::Spring(spring) => {
+                let mut remaining = dt.min(SPRING_MAX_FRAME);
+                while remaining > 0.0 {
+                    let h = remaining.min(SPRING_SUBSTEP);
+                    let acceleration = (self.target - self.value) * spring.stiffness - self.velocity * spring.damping;
+                    self.velocity = self.velocity + acceleration * h;
+                    self.value = self.value + self.velocity * h;
+                    remaining -= h;
+                }
+                if (self.target - self.value).norm() < spring.precision && self.velocity.norm() < spring.precision {
+                    return self.finish();
+                }
+            }
+        }
+        false
+    }
+
+    /// Advances by this frame's time at the `WidgetMotionSettings` speed, or
+    /// straight to the target when motion is reduced. Returns true on the
+    /// frame that reaches the target.
+    pub fn update(&mut self, transition: &Transition, motion: &WidgetMotionSettings, time: &Time) -> bool {
+        if self.settled {
+            false
+        } else if motion.snaps() {
+            self.finish()
+        } else {
+            self.step(transition, motion.delta_secs(time))
+        }
+    }
+
+    fn finish(&mut self) -> bool {
+        self.value = self.target;
+        self.velocity = T::ZERO;
+        self.settled = true;
+        true
+    }
+}
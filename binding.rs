@@ -0,0 +1,173 @@
+use bevy::ecs::component::{Mutable, Tick};
+use bevy::prelude::*;
+use std::sync::Arc;
+
+/// Reads and writes one field of `T`, e.g. a setting inside a resource.
+/// Name `T` when building one so the closures' argument types are known.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_widgets::{toggle::ToggleBuilder, Lens};
+/// #[derive(Resource)]
+/// struct AudioSettings {
+///     muted: bool,
+/// }
+///
+/// fn spawn_mute_toggle(mut commands: Commands) {
+///     ToggleBuilder::new(&mut commands)
+///         .bind_to(Lens::<AudioSettings, _>::new(|s| s.muted, |s, muted| s.muted = muted))
+///         .spawn();
+/// }
+/// ```
+pub struct Lens<T, V> {
+    get: Box<dyn Fn(&T) -> V + Send + Sync>,
+    set: Box<dyn Fn(&mut T, V) + Send + Sync>,
+}
+
+impl<T, V> Lens<T, V> {
+    pub fn new(
+        get: impl Fn(&T) -> V + Send + Sync + 'static,
+        set: impl Fn(&mut T, V) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            get: Box::new(get),
+            set: Box::new(set),
+        }
+    }
+}
+
+/// Widget state that can be kept in sync with app data.
+pub trait BindableWidget: Component {
+    type Value: Clone + PartialEq + Send + Sync + 'static;
+
+    fn bound_value(&self) -> Self::Value;
+
+    /// Applies a value coming from the bound data. Must not send change
+    /// events, so listeners don't echo it back.
+    fn apply_bound_value(entity: EntityWorldMut, value: Self::Value);
+}
+
+type SyncFn = Box<dyn FnMut(&mut World, Entity) + Send + Sync>;
+
+/// Two-way link between a widget and a field of a resource or component.
+///
+/// The bound data wins when the widget first appears. After that, whichever
+/// side changed since the last sync is copied to the other, and the widget
+/// wins if both did. Values are compared before writing, so neither side is
+/// marked changed unless it really differs and nothing ping-pongs.
+#[derive(Component)]
+pub struct WidgetBinding {
+    sync: Option<SyncFn>,
+}
+
+impl WidgetBinding {
+    pub fn resource<W: BindableWidget, R: Resource>(lens: Lens<R, W::Value>) -> Self {
+        let lens = Arc::new(lens);
+        let read_lens = lens.clone();
+        Self::new::<W>(
+            move |world| {
+                let resource = world.get_resource_ref::<R>()?;
+                Some(((read_lens.get)(&resource), resource.last_changed()))
+            },
+            move |world, value| {
+                if let Some(mut resource) = world.get_resource_mut::<R>() {
+                    (lens.set)(&mut resource, value);
+                }
+            },
+        )
+    }
+
+    pub fn component<W: BindableWidget, C: Component<Mutability = Mutable>>(
+        target: Entity,
+        lens: Lens<C, W::Value>,
+    ) -> Self {
+        let lens = Arc::new(lens);
+        let read_lens = lens.clone();
+        Self::new::<W>(
+            move |world| {
+                let component = world.get_entity(target).ok()?.get_ref::<C>()?;
+                Some(((read_lens.get)(&component), component.last_changed()))
+            },
+            move |world, value| {
+                if let Some(mut component) = world.get_mut::<C>(target) {
+                    (lens.set)(&mut component, value);
+                }
+            },
+        )
+    }
+
+    fn new<W: BindableWidget>(
+        read: impl Fn(&World) -> Option<(W::Value, Tick)> + Send + Sync + 'static,
+        write: impl Fn(&mut World, W::Value) + Send + Sync + 'static,
+    ) -> Self {
+        let mut synced: Option<W::Value> = None;
+        let mut last_run = Tick::new(0);
+
+        let sync = move |world: &mut World, entity: Entity| {
+            let this_run = world.change_tick();
+            let Some(widget) = world.get_entity(entity).ok().and_then(|entity| entity.get_ref::<W>()) else {
+                return;
+            };
+            let widget_value = widget.bound_value();
+            let widget_changed = widget.last_changed().is_newer_than(last_run, this_run);
+            let Some((source_value, source_tick)) = read(world) else {
+                return;
+            };
+            let source_changed = source_tick.is_newer_than(last_run, this_run);
+            last_run = this_run;
+
+            let widget_moved = widget_changed && synced.as_ref() != Some(&widget_value);
+            let source_moved = source_changed && synced.as_ref() != Some(&source_value);
+
+            if synced.is_some() && widget_moved {
+                if source_value != widget_value {
+                    write(world, widget_value.clone());
+                }
+                synced = Some(widget_value);
+            } else if synced.is_none() || source_moved {
+                if source_value != widget_value {
+                    W::apply_bound_value(world.entity_mut(entity), source_value.clone());
+                }
+                synced = Some(source_value);
+            }
+        };
+
+        Self {
+            sync: Some(Box::new(sync)),
+        }
+    }
+}
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct WidgetBindingSystem;
+
+/// Runs every `WidgetBinding`. Widget plugins order `WidgetBindingSystem`
+/// between their input and visual sets.
+pub struct WidgetBindingPlugin;
+
+impl Plugin for WidgetBindingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, widget_binding_system.in_set(WidgetBindingSystem));
+    }
+}
+
+pub fn widget_binding_system(world: &mut World) {
+    let bound: Vec<Entity> = world
+        .query_filtered::<Entity, With<WidgetBinding>>()
+        .iter(world)
+        .collect();
+
+    for entity in bound {
+        // Taken out for the call so the closure can borrow the world mutably
+        let Some(mut sync) = world
+            .get_mut::<WidgetBinding>(entity)
+            .and_then(|mut binding| binding.sync.take())
+        else {
+            continue;
+        };
+        sync(world, entity);
+        if let Some(mut binding) = world.get_mut::<WidgetBinding>(entity) {
+            binding.sync = Some(sync);
+        }
+    }
+}
@@ -1,4 +1,8 @@
 use bevy::prelude::*;
+use bevy::ecs::component::Mutable;
+use crate::binding::{Lens, WidgetBinding};
+use crate::focus::{FocusRingTarget, Focusable};
+use crate::theme::{WidgetTheme, WidgetThemeOverride};
 use super::resources::DropdownOptionRegistry;
 use bevy::ui::*;
 use super::components::{ChildOf as DropdownChildOf, *};
@@ -6,6 +10,9 @@ use super::components::{ChildOf as DropdownChildOf, *};
 pub struct DropdownBuilder {
     options: Vec<(String, Option<Handle<Image>>)>,
     config: DropdownConfig,
+    binding: Option<WidgetBinding>,
+    theme: Option<WidgetTheme>,
+    focusable: Focusable,
 }
 
 impl DropdownBuilder {
@@ -13,6 +20,9 @@ impl DropdownBuilder {
         Self {
             options: Vec::new(),
             config: DropdownConfig::default(),
+            binding: None,
+            theme: None,
+            focusable: Focusable::default(),
         }
     }
     
@@ -31,10 +41,41 @@ impl DropdownBuilder {
         self
     }
     
+    /// Position in Tab order; see `Focusable`.
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.focusable = Focusable::with_tab_index(tab_index);
+        self
+    }
+
+    /// Styles this dropdown with `theme` instead of the global `WidgetTheme`.
+    pub fn with_theme(mut self, theme: WidgetTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Keeps the selected option index and a field of resource `R` in sync both ways.
+    pub fn bind_to<R: Resource>(mut self, lens: Lens<R, Option<usize>>) -> Self {
+        self.binding = Some(WidgetBinding::resource::<Dropdown, R>(lens));
+        self
+    }
+
+    /// Keeps the selected option index and a field of component `C` on `target` in sync both ways.
+    pub fn bind_to_component<C: Component<Mutability = Mutable>>(
+        mut self,
+        target: Entity,
+        lens: Lens<C, Option<usize>>,
+    ) -> Self {
+        self.binding = Some(WidgetBinding::component::<Dropdown, C>(target, lens));
+        self
+    }
+
     pub fn build(self) -> DropdownSpawnCommand {
         DropdownSpawnCommand {
             options: self.options,
             config: self.config,
+            binding: self.binding,
+            theme: self.theme,
+            focusable: self.focusable,
         }
     }
 }
@@ -42,6 +83,9 @@ impl DropdownBuilder {
 pub struct DropdownSpawnCommand {
     options: Vec<(String, Option<Handle<Image>>)>,
     config: DropdownConfig,
+    binding: Option<WidgetBinding>,
+    theme: Option<WidgetTheme>,
+    focusable: Focusable,
 }
 
 impl DropdownSpawnCommand {
@@ -62,9 +106,6 @@ impl DropdownSpawnCommand {
                 margin: UiRect::all(Val::Px(5.0)),
                 ..default()
             },
-            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.8)),
-            BorderColor(Color::srgb(0.3, 0.3, 0.3)),
-            BorderRadius::all(Val::Px(4.0)),
             Dropdown {
                 option_ids: option_ids.clone(),
                 selected_id: None,
@@ -73,9 +114,11 @@ impl DropdownSpawnCommand {
             },
             config.clone(),
             DropdownAnimation::default(),
+            DropdownHighlight::default(),
             Interaction::None,
             FocusPolicy::Block,
             DropdownNeedsVisualUpdate,
+            self.focusable,
         )).id();
         
         // Spawn button
@@ -86,18 +129,9 @@ impl DropdownSpawnCommand {
                 height: Val::Px(40.0),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
-                padding: UiRect::all(Val::Px(8.0)),
                 ..default()
             },
-            BackgroundColor(Color::srgb(0.9, 0.9, 0.9)),
-            BorderColor(Color::srgb(0.6, 0.6, 0.6)),
-            BorderRadius::all(Val::Px(4.0)),
             Text::new(&config.placeholder),
-            TextFont {
-                font_size: 14.0,
-                ..default()
-            },
-            TextColor(Color::srgb(0.2, 0.2, 0.2)),
             DropdownButton,
         )).id();
         
@@ -140,16 +174,9 @@ impl DropdownSpawnCommand {
                         height: Val::Px(30.0),
                         justify_content: JustifyContent::Start,
                         align_items: AlignItems::Center,
-                        padding: UiRect::horizontal(Val::Px(10.0)),
                         ..default()
                     },
-                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
                     Text::new(&option_data.label),
-                    TextFont {
-                        font_size: 12.0,
-                        ..default()
-                    },
-                    TextColor(Color::WHITE),
                     DropdownOptionElement(*option_id),
                     DropdownChildOf::new(list_entity),
                 )).id();
@@ -161,11 +188,20 @@ impl DropdownSpawnCommand {
         
         // Add ChildOf components for the main children
         commands.entity(button_entity).insert(DropdownChildOf::new(dropdown_entity));
+        commands.entity(dropdown_entity).insert(FocusRingTarget(button_entity));
         commands.entity(list_entity).insert(DropdownChildOf::new(dropdown_entity));
         commands.entity(backdrop_entity).insert(DropdownChildOf::new(dropdown_entity));
         
         commands.entity(dropdown_entity)
             .add_children(&[button_entity, list_entity, backdrop_entity]);
+
+        if let Some(binding) = self.binding {
+            commands.entity(dropdown_entity).insert(binding);
+        }
+
+        if let Some(theme) = self.theme {
+            commands.entity(dropdown_entity).insert(WidgetThemeOverride(theme));
+        }
         
         dropdown_entity
     }
@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+use crate::binding::BindableWidget;
+use super::{components::*, events::*, resources::*};
+
+/// Change a dropdown's selection from code. Passing `None` clears it.
+/// `select_dropdown_option_silently` skips the event and `on_change`
+/// callback, e.g. when restoring saved state.
+pub trait DropdownCommandsExt {
+    fn select_dropdown_option(&mut self, option_id: Option<DropdownOptionId>) -> &mut Self;
+    fn select_dropdown_option_silently(&mut self, option_id: Option<DropdownOptionId>) -> &mut Self;
+}
+
+impl DropdownCommandsExt for EntityCommands<'_> {
+    fn select_dropdown_option(&mut self, option_id: Option<DropdownOptionId>) -> &mut Self {
+        self.queue(select_option(option_id, true))
+    }
+
+    fn select_dropdown_option_silently(&mut self, option_id: Option<DropdownOptionId>) -> &mut Self {
+        self.queue(select_option(option_id, false))
+    }
+}
+
+fn select_option(
+    option_id: Option<DropdownOptionId>,
+    notify: bool,
+) -> impl FnOnce(EntityWorldMut) + Send + 'static {
+    move |mut entity: EntityWorldMut| {
+        let dropdown_entity = entity.id();
+        let Some(mut dropdown) = entity.get_mut::<Dropdown>() else {
+            return;
+        };
+        if option_id.is_some_and(|id| !dropdown.option_ids.contains(&id)) {
+            warn!("Option {:?} doesn't belong to dropdown {:?}", option_id, dropdown_entity);
+            return;
+        }
+        let previous_id = dropdown.selected_id;
+        if previous_id == option_id {
+            return;
+        }
+        dropdown.selected_id = option_id;
+
+        if notify {
+            if let Some(callback) = &dropdown.on_change {
+                callback(option_id);
+            }
+        }
+        entity.insert(DropdownNeedsVisualUpdate);
+
+        if notify {
+            entity.world_scope(|world| {
+                let label = |id: Option<DropdownOptionId>| {
+                    let registry = world.get_resource::<DropdownOptionRegistry>()?;
+                    registry.options.get(&id?).map(|option| option.label.clone())
+                };
+                let event = DropdownChangedEvent {
+                    dropdown_entity,
+                    kind: DropdownChangeKind::Programmatic,
+                    previous_id,
+                    new_id: option_id,
+                    previous_label: label(previous_id),
+                    new_label: label(option_id),
+                };
+                world.send_event(event);
+            });
+        }
+    }
+}
+
+/// Dropdowns bind to the selected option's index, which is stable across
+/// runs unlike registry ids.
+impl BindableWidget for Dropdown {
+    type Value = Option<usize>;
+
+    fn bound_value(&self) -> Option<usize> {
+        let selected_id = self.selected_id?;
+        self.option_ids.iter().position(|id| *id == selected_id)
+    }
+
+    fn apply_bound_value(entity: EntityWorldMut, value: Option<usize>) {
+        let option_id = match (value, entity.get::<Dropdown>()) {
+            (Some(index), Some(dropdown)) => match dropdown.option_ids.get(index) {
+                Some(id) => Some(*id),
+                None => return,
+            },
+            _ => None,
+        };
+        select_option(option_id, false)(entity);
+    }
+}
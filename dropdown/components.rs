@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::anim::{AnimatedValue, Spring, Transition};
 
 #[derive(Component, Reflect)]
 pub struct Dropdown {
@@ -37,7 +38,8 @@ pub struct DropdownConfig {
     pub direction: DropdownDirection,
     pub searchable: bool,
     pub placeholder: String,
-    pub animation_config: AnimationConfig,
+    /// How the list opens and closes
+    pub transition: Transition,
 }
 
 impl Default for DropdownConfig {
@@ -47,11 +49,7 @@ impl Default for DropdownConfig {
             direction: DropdownDirection::Auto,
             searchable: false,
             placeholder: "Select an option...".to_string(),
-            animation_config: AnimationConfig {
-                stiffness: 170.0,
-                damping: 26.0,
-                precision: 0.01,
-            },
+            transition: Spring::default().into(),
         }
     }
 }
@@ -63,13 +61,28 @@ pub enum DropdownDirection {
     Auto,
 }
 
-#[derive(Component, Debug, Clone, Copy, Reflect)]
+/// Spring settings for the list from before `DropdownConfig::transition`.
+/// Converts into the equivalent `Spring` transition.
+#[deprecated(note = "set `DropdownConfig::transition` to a `Spring`")]
+#[derive(Debug, Clone, Copy)]
 pub struct AnimationConfig {
     pub stiffness: f32,
     pub damping: f32,
     pub precision: f32,
 }
 
+#[allow(deprecated)]
+impl From<AnimationConfig> for Transition {
+    fn from(config: AnimationConfig) -> Self {
+        Spring {
+            stiffness: config.stiffness,
+            damping: config.damping,
+            precision: config.precision,
+        }
+        .into()
+    }
+}
+
 #[derive(Component)]
 pub struct DropdownButton;
 
@@ -82,17 +95,17 @@ pub struct DropdownOptionElement(pub DropdownOptionId);
 #[derive(Component)]
 pub struct DropdownBackdrop;
 
-#[derive(Component)]
-pub struct DropdownFocused;
-
 #[derive(Component)]
 pub struct DropdownNeedsVisualUpdate;
 
+/// Option the arrow keys have moved to while the list is open
+#[derive(Component, Default, PartialEq)]
+pub struct DropdownHighlight(pub Option<DropdownOptionId>);
+
 #[derive(Component, Default)]
 pub struct DropdownAnimation {
-    pub progress: f32,
-    pub target_progress: f32,
-    pub velocity: f32,
+    /// 0 when closed, 1 when fully open
+    pub progress: AnimatedValue<f32>,
 }
 
 pub type DropdownOptionId = u32;
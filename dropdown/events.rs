@@ -6,6 +6,8 @@ pub enum DropdownChangeKind {
     Opened,
     Closed,
     SelectionChanged,
+    /// Selection changed from code, through `DropdownCommandsExt` or a binding
+    Programmatic,
     Cancelled,
 }
 
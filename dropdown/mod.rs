@@ -3,18 +3,32 @@ pub mod resources;
 pub mod events;
 pub mod systems;
 pub mod builder;
+pub mod commands;
 pub use components::*;
 pub use events::*;
 pub use resources::*;
 pub use builder::{DropdownBuilder};
+pub use commands::DropdownCommandsExt;
 
 use bevy::prelude::*;
+use crate::binding::{WidgetBindingPlugin, WidgetBindingSystem};
+use crate::focus::{WidgetFocusPlugin, WidgetFocusSystem};
+use crate::input::WidgetInputPlugin;
+use crate::motion::WidgetMotionPlugin;
+use crate::theme::WidgetThemePlugin;
+use crate::WidgetAppExt;
 use systems::*;
 
 pub struct DropdownPlugin;
 
 impl Plugin for DropdownPlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugin_once(WidgetInputPlugin);
+        app.add_plugin_once(WidgetFocusPlugin);
+        app.add_plugin_once(WidgetThemePlugin);
+        app.add_plugin_once(WidgetMotionPlugin);
+        app.add_plugin_once(WidgetBindingPlugin);
+
         app
             .init_resource::<DropdownOptionRegistry>()
             .init_resource::<UiZIndexAllocator>()
@@ -24,12 +38,17 @@ impl Plugin for DropdownPlugin {
                 (
                     DropdownSystem::ProcessInput
                         .after(bevy::input::InputSystem)
+                        .after(WidgetFocusSystem)
                         .before(bevy::ui::UiSystem::Layout),
                     DropdownSystem::UpdateAnimation.after(DropdownSystem::ProcessInput),
                     DropdownSystem::UpdateVisuals.after(DropdownSystem::UpdateAnimation),
                 )
                 .chain(),
             )
+            .configure_sets(
+                Update,
+                WidgetBindingSystem.after(DropdownSystem::ProcessInput).before(DropdownSystem::UpdateVisuals),
+            )
             .add_systems(Update, (
                 dropdown_toggle_system.in_set(DropdownSystem::ProcessInput),
                 dropdown_backdrop_system.in_set(DropdownSystem::ProcessInput),
@@ -37,8 +56,10 @@ impl Plugin for DropdownPlugin {
                 dropdown_keyboard_system.in_set(DropdownSystem::ProcessInput),
                 dropdown_animation_system.in_set(DropdownSystem::UpdateAnimation),
                 dropdown_visual_update_system.in_set(DropdownSystem::UpdateVisuals),
+                dropdown_theme_system.in_set(DropdownSystem::UpdateVisuals),
+                dropdown_state_colors_system.in_set(DropdownSystem::UpdateVisuals),
+                dropdown_accessibility_system.in_set(DropdownSystem::UpdateVisuals),
                 dropdown_z_index_system,
-                dropdown_focus_management_system,
             ));
     }
 }
\ No newline at end of file
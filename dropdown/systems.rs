@@ -1,5 +1,11 @@
+use accesskit::Role;
+use bevy::a11y::AccessibilityNode;
 use bevy::prelude::*;
 use super::{components::{ChildOf as DropdownChildOf, *}, events::*, resources::*};
+use crate::accessibility::{needs_accessibility_sync, sync_accessibility_node};
+use crate::focus::WidgetFocused;
+use crate::motion::WidgetMotionSettings;
+use crate::theme::{ThemeLookup, WidgetVisualState};
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum DropdownSystem {
@@ -36,12 +42,6 @@ pub fn dropdown_toggle_system(
                     });
                     
                     commands.entity(dropdown_entity).insert(DropdownNeedsVisualUpdate);
-                    
-                    if dropdown.is_open {
-                        commands.entity(dropdown_entity).insert(DropdownFocused);
-                    } else {
-                        commands.entity(dropdown_entity).remove::<DropdownFocused>();
-                    }
                 }
             }
         }
@@ -70,9 +70,7 @@ pub fn dropdown_backdrop_system(
                         new_label: None,
                     });
                     
-                    commands.entity(dropdown_entity)
-                        .insert(DropdownNeedsVisualUpdate)
-                        .remove::<DropdownFocused>();
+                    commands.entity(dropdown_entity).insert(DropdownNeedsVisualUpdate);
                 }
             }
         }
@@ -84,6 +82,7 @@ pub fn dropdown_option_select_system(
     option_registry: Res<DropdownOptionRegistry>,
     mut q_dropdowns: Query<(Entity, &mut Dropdown)>,
     q_option_parents: Query<&DropdownChildOf, With<DropdownOptionElement>>,
+    q_list_parents: Query<&DropdownChildOf, With<DropdownList>>,
     mut q_options: Query<(Entity, &Interaction, &DropdownOptionElement), Changed<Interaction>>,
     mut evw_dropdown_change: EventWriter<DropdownChangedEvent>,
 ) {
@@ -91,7 +90,7 @@ pub fn dropdown_option_select_system(
         if let Interaction::Pressed = interaction {
             if let Ok(parent) = q_option_parents.get(option_entity) {
                 // The parent is the list entity, we need the grandparent (dropdown entity)
-                if let Ok(list_parent) = q_option_parents.get(parent.parent()) {
+                if let Ok(list_parent) = q_list_parents.get(parent.parent()) {
                     if let Ok((dropdown_entity, mut dropdown)) = q_dropdowns.get_mut(list_parent.parent()) {
                         let previous_id = dropdown.selected_id;
                         let previous_label = previous_id.and_then(|id| 
@@ -113,9 +112,7 @@ pub fn dropdown_option_select_system(
                             new_label,
                         });
                         
-                        commands.entity(dropdown_entity)
-                            .insert(DropdownNeedsVisualUpdate)
-                            .remove::<DropdownFocused>();
+                        commands.entity(dropdown_entity).insert(DropdownNeedsVisualUpdate);
                         
                         if let Some(callback) = &dropdown.on_change {
                             callback(Some(option_element.0));
@@ -128,26 +125,22 @@ pub fn dropdown_option_select_system(
 }
 
 pub fn dropdown_animation_system(
+    mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(&mut DropdownAnimation, &DropdownConfig, &Dropdown)>,
+    motion: Res<WidgetMotionSettings>,
+    mut query: Query<(Entity, &mut DropdownAnimation, &DropdownConfig, &Dropdown)>,
 ) {
-    for (mut animation, config, dropdown) in &mut query {
+    for (entity, mut animation, config, dropdown) in &mut query {
         let target = if dropdown.is_open { 1.0 } else { 0.0 };
-        animation.target_progress = target;
-        
-        let displacement = animation.target_progress - animation.progress;
-        let spring_force = config.animation_config.stiffness * displacement;
-        let damping_force = config.animation_config.damping * animation.velocity;
-        let acceleration = spring_force - damping_force;
+        if animation.progress.is_settled() && animation.progress.target() == target {
+            continue;
+        }
         
-        animation.velocity += acceleration * time.delta_secs();
-        animation.progress += animation.velocity * time.delta_secs();
+        animation.progress.set_target(target);
+        animation.progress.update(&config.transition, &motion, &time);
         
-        if displacement.abs() < config.animation_config.precision 
-            && animation.velocity.abs() < config.animation_config.precision {
-            animation.progress = animation.target_progress;
-            animation.velocity = 0.0;
-        }
+        // The list follows the animation every frame until it settles
+        commands.entity(entity).insert(DropdownNeedsVisualUpdate);
     }
 }
 
@@ -175,9 +168,10 @@ pub fn dropdown_visual_update_system(
                     Val::Px(px) => px,
                     _ => 200.0,
                 };
-                let height = animation.progress * max_height;
+                let progress = animation.progress.value();
+                let height = progress * max_height;
                 
-                if animation.progress <= config.animation_config.precision {
+                if progress <= 0.01 {
                     *visibility = Visibility::Hidden;
                 } else {
                     *visibility = Visibility::Visible;
@@ -190,7 +184,7 @@ pub fn dropdown_visual_update_system(
                         transform.translation.y = 0.0;
                     }
                     DropdownDirection::Up => {
-                        transform.translation.y = -height * (1.0 - animation.progress);
+                        transform.translation.y = -height * (1.0 - progress);
                     }
                     DropdownDirection::Auto => {
                         transform.translation.y = 0.0;
@@ -203,53 +197,243 @@ pub fn dropdown_visual_update_system(
     }
 }
 
-pub fn dropdown_z_index_system(
-    mut allocator: ResMut<UiZIndexAllocator>,
-    mut q_dropdowns: Query<(&mut ZIndex, &DropdownAnimation), With<DropdownList>>,
+/// Applies theme colors, fonts and spacing to new dropdowns, and to every
+/// dropdown when the theme changes.
+pub fn dropdown_theme_system(
+    themes: ThemeLookup,
+    mut q_frames: Query<(Entity, Ref<Dropdown>, &mut BackgroundColor, &mut BorderColor, &mut BorderRadius)>,
+    mut q_buttons: Query<
+        (Entity, Ref<DropdownButton>, &mut Node, &mut BorderColor, &mut BorderRadius, &mut TextColor, &mut TextFont),
+        Without<Dropdown>
+    >,
+    mut q_options: Query<
+        (Entity, Ref<DropdownOptionElement>, &mut Node, &mut TextColor, &mut TextFont),
+        Without<DropdownButton>
+    >,
 ) {
-    for (mut z_index, animation) in &mut q_dropdowns {
-        if animation.progress > 0.0 && z_index.0 == 0 {
-            z_index.0 = allocator.next();
-        } else if animation.progress <= 0.01 && z_index.0 > 0 {
-            z_index.0 = 0;
+    let restyle_all = themes.changed();
+
+    for (entity, dropdown, mut background, mut border, mut radius) in &mut q_frames {
+        if !restyle_all && !dropdown.is_added() {
+            continue;
         }
+        let theme = themes.get(entity);
+        background.0 = theme.palette.surface;
+        border.0 = theme.palette.border;
+        *radius = BorderRadius::all(Val::Px(theme.radii.control));
+    }
+
+    for (entity, button, mut node, mut border, mut radius, mut color, mut font) in &mut q_buttons {
+        if !restyle_all && !button.is_added() {
+            continue;
+        }
+        let theme = themes.get(entity);
+        node.padding = UiRect::all(Val::Px(theme.spacing.padding));
+        border.0 = theme.palette.border;
+        *radius = BorderRadius::all(Val::Px(theme.radii.control));
+        color.0 = theme.palette.control_text;
+        *font = theme.typography.text_font(theme.typography.body_size);
+    }
+
+    for (entity, option, mut node, mut color, mut font) in &mut q_options {
+        if !restyle_all && !option.is_added() {
+            continue;
+        }
+        let theme = themes.get(entity);
+        node.padding = UiRect::horizontal(Val::Px(theme.spacing.item_padding));
+        color.0 = theme.palette.control_text;
+        *font = theme.typography.text_font(theme.typography.label_size);
+    }
+}
+
+/// Colors the button and options for their hover/press state, with the
+/// chosen option shown as selected and the keyboard highlight as hovered.
+pub fn dropdown_state_colors_system(
+    themes: ThemeLookup,
+    q_dropdowns: Query<(Ref<Dropdown>, Ref<DropdownHighlight>)>,
+    q_lists: Query<&DropdownChildOf, With<DropdownList>>,
+    mut q_buttons: Query<(Entity, Ref<Interaction>, &mut BackgroundColor), With<DropdownButton>>,
+    mut q_options: Query<
+        (Entity, Ref<Interaction>, &DropdownOptionElement, &DropdownChildOf, &mut BackgroundColor),
+        Without<DropdownButton>
+    >,
+) {
+    let restyle_all = themes.changed();
+
+    for (entity, interaction, mut background) in &mut q_buttons {
+        if !restyle_all && !interaction.is_changed() {
+            continue;
+        }
+        let state = WidgetVisualState::from_interaction(*interaction);
+        background.0 = themes.get(entity).palette.controls.pick(state);
+    }
+
+    for (entity, interaction, option, list, mut background) in &mut q_options {
+        let Some((dropdown, highlight)) = q_lists
+            .get(list.parent())
+            .ok()
+            .and_then(|list_parent| q_dropdowns.get(list_parent.parent()).ok())
+        else {
+            continue;
+        };
+        if !restyle_all && !interaction.is_changed() && !dropdown.is_changed() && !highlight.is_changed() {
+            continue;
+        }
+        let interaction_state = WidgetVisualState::from_interaction(*interaction);
+        let state = WidgetVisualState {
+            selected: dropdown.selected_id == Some(option.0),
+            hovered: interaction_state.hovered || (dropdown.is_open && highlight.0 == Some(option.0)),
+            ..interaction_state
+        };
+        background.0 = themes.get(entity).palette.controls.pick(state);
     }
 }
 
-pub fn dropdown_focus_management_system(
+/// Publishes each dropdown as a combobox showing the selected label, with its
+/// list as a listbox of options.
+pub fn dropdown_accessibility_system(
     mut commands: Commands,
-    q_dropdowns: Query<(Entity, &DropdownAnimation, &DropdownConfig)>,
-    q_focused: Query<Entity, With<DropdownFocused>>,
+    option_registry: Res<DropdownOptionRegistry>,
+    mut q_dropdowns: Query<(Entity, Ref<Dropdown>, Option<&mut AccessibilityNode>)>,
+    mut q_lists: Query<
+        (Entity, &DropdownChildOf, Option<&mut AccessibilityNode>),
+        (With<DropdownList>, Without<Dropdown>)
+    >,
+    mut q_options: Query<
+        (Entity, &DropdownOptionElement, &DropdownChildOf, Option<&mut AccessibilityNode>),
+        (Without<Dropdown>, Without<DropdownList>)
+    >,
+    q_dropdown_state: Query<Ref<Dropdown>>,
 ) {
-    for (entity, animation, config) in &q_dropdowns {
-        if animation.progress <= config.animation_config.precision {
-            if let Ok(focused_entity) = q_focused.get(entity) {
-                commands.entity(focused_entity).remove::<DropdownFocused>();
+    let label = |id: DropdownOptionId| option_registry.options.get(&id).map(|option| option.label.clone());
+    for (entity, dropdown, node) in &mut q_dropdowns {
+        if !needs_accessibility_sync(dropdown.is_changed(), &node) {
+            continue;
+        }
+        sync_accessibility_node(&mut commands, entity, node, Role::ComboBox, |node| {
+            node.set_expanded(dropdown.is_open);
+            match dropdown.selected_id.and_then(label) {
+                Some(selected) => node.set_value(selected),
+                None => node.clear_value(),
+            }
+        });
+    }
+
+    for (entity, parent, node) in &mut q_lists {
+        let dropdown_changed = q_dropdown_state
+            .get(parent.parent())
+            .is_ok_and(|dropdown| dropdown.is_changed());
+        if needs_accessibility_sync(dropdown_changed, &node) {
+            sync_accessibility_node(&mut commands, entity, node, Role::ListBox, |_| {});
+        }
+    }
+
+    for (entity, option, list, node) in &mut q_options {
+        let Some(dropdown) = q_lists
+            .get(list.parent())
+            .ok()
+            .and_then(|(_, list_parent, _)| q_dropdown_state.get(list_parent.parent()).ok())
+        else {
+            continue;
+        };
+        if !needs_accessibility_sync(dropdown.is_changed(), &node) {
+            continue;
+        }
+        sync_accessibility_node(&mut commands, entity, node, Role::ListBoxOption, |node| {
+            if let Some(option_label) = label(option.0) {
+                node.set_label(option_label);
             }
+            node.set_selected(dropdown.selected_id == Some(option.0));
+        });
+    }
+}
+
+pub fn dropdown_z_index_system(
+    mut allocator: ResMut<UiZIndexAllocator>,
+    mut q_dropdowns: Query<(&mut ZIndex, &DropdownAnimation), With<DropdownList>>,
+) {
+    for (mut z_index, animation) in &mut q_dropdowns {
+        let progress = animation.progress.value();
+        if progress > 0.0 && z_index.0 == 0 {
+            z_index.0 = allocator.next();
+        } else if progress <= 0.01 && z_index.0 > 0 {
+            z_index.0 = 0;
         }
     }
 }
 
+/// Enter, Space or ArrowDown open the focused dropdown. While it is open the
+/// arrow keys, Home and End move the highlight, Enter or Space pick the
+/// highlighted option and Escape closes it.
 pub fn dropdown_keyboard_system(
-    mut q_dropdowns: Query<(Entity, &mut Dropdown, &DropdownAnimation), With<DropdownFocused>>,
+    mut q_dropdowns: Query<(Entity, &mut Dropdown, &mut DropdownHighlight), With<WidgetFocused>>,
     keys: Res<ButtonInput<KeyCode>>,
+    option_registry: Res<DropdownOptionRegistry>,
     mut evw_dropdown_change: EventWriter<DropdownChangedEvent>,
     mut commands: Commands,
 ) {
-    for (entity, mut dropdown, animation) in &mut q_dropdowns {
-        if keys.just_pressed(KeyCode::Escape) && dropdown.is_open {
-            dropdown.is_open = false;
-            
+    let label = |id: Option<DropdownOptionId>| {
+        id.and_then(|id| option_registry.options.get(&id).map(|option| option.label.clone()))
+    };
+    let activate = keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::Space);
+
+    for (entity, mut dropdown, mut highlight) in &mut q_dropdowns {
+        let mut event = |kind: DropdownChangeKind, previous_id: Option<DropdownOptionId>, new_id: Option<DropdownOptionId>| {
+            let changed = kind == DropdownChangeKind::SelectionChanged;
             evw_dropdown_change.write(DropdownChangedEvent {
                 dropdown_entity: entity,
-                kind: DropdownChangeKind::Cancelled,
-                previous_id: dropdown.selected_id,
-                new_id: dropdown.selected_id,
-                previous_label: None,
-                new_label: None,
+                kind,
+                previous_id,
+                new_id,
+                previous_label: if changed { label(previous_id) } else { None },
+                new_label: if changed { label(new_id) } else { None },
             });
-            
             commands.entity(entity).insert(DropdownNeedsVisualUpdate);
+        };
+
+        if !dropdown.is_open {
+            if activate || keys.just_pressed(KeyCode::ArrowDown) {
+                dropdown.is_open = true;
+                highlight.0 = dropdown.selected_id.or(dropdown.option_ids.first().copied());
+                event(DropdownChangeKind::Opened, dropdown.selected_id, dropdown.selected_id);
+            }
+            continue;
+        }
+
+        if keys.just_pressed(KeyCode::Escape) {
+            dropdown.is_open = false;
+            event(DropdownChangeKind::Cancelled, dropdown.selected_id, dropdown.selected_id);
+            continue;
+        }
+
+        if activate {
+            let previous_id = dropdown.selected_id;
+            dropdown.is_open = false;
+            if highlight.0.is_some() && highlight.0 != previous_id {
+                dropdown.selected_id = highlight.0;
+                event(DropdownChangeKind::SelectionChanged, previous_id, highlight.0);
+                if let Some(callback) = &dropdown.on_change {
+                    callback(highlight.0);
+                }
+            } else {
+                event(DropdownChangeKind::Closed, previous_id, previous_id);
+            }
+            continue;
         }
+
+        let last = dropdown.option_ids.len().saturating_sub(1);
+        let current = highlight.0.and_then(|id| dropdown.option_ids.iter().position(|option| *option == id));
+        let next = if keys.just_pressed(KeyCode::ArrowDown) {
+            current.map_or(0, |index| (index + 1).min(last))
+        } else if keys.just_pressed(KeyCode::ArrowUp) {
+            current.map_or(last, |index| index.saturating_sub(1))
+        } else if keys.just_pressed(KeyCode::Home) {
+            0
+        } else if keys.just_pressed(KeyCode::End) {
+            last
+        } else {
+            continue;
+        };
+        highlight.set_if_neq(DropdownHighlight(dropdown.option_ids.get(next).copied()));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,371 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::ui::{ComputedNode, FocusPolicy};
+
+use crate::input::WidgetInputPlugin;
+use crate::theme::{FocusRingStyle, ThemeLookup, WidgetThemePlugin};
+use crate::WidgetAppExt;
+
+/// The one widget that receives keyboard input.
+///
+/// Clicking a widget focuses it and Tab/Shift+Tab walks the `Focusable`s in
+/// order. Setting it from a system moves focus programmatically. The focused
+/// entity also carries `WidgetFocused`, which widget keyboard systems filter
+/// on.
+#[derive(Resource, Debug, Default)]
+pub struct WidgetFocus {
+    focused: Option<Entity>,
+    /// Whether the last input was a key rather than a mouse press
+    visible: bool,
+    /// Open scopes, innermost last, with the focus to restore when they close
+    scopes: Vec<(Entity, Option<Entity>)>,
+}
+
+impl WidgetFocus {
+    pub fn get(&self) -> Option<Entity> {
+        self.focused
+    }
+
+    pub fn is_focused(&self, entity: Entity) -> bool {
+        self.focused == Some(entity)
+    }
+
+    pub fn set(&mut self, entity: Entity) {
+        self.focused = Some(entity);
+    }
+
+    pub fn clear(&mut self) {
+        self.focused = None;
+    }
+
+    /// Whether focus should be shown, like CSS `:focus-visible`. True after
+    /// keyboard input and false after a mouse press.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// The innermost `FocusScope` that Tab traversal is confined to.
+    pub fn active_scope(&self) -> Option<Entity> {
+        self.scopes.last().map(|(scope, _)| *scope)
+    }
+}
+
+/// Marks the entity that `WidgetFocus` points at.
+#[derive(Component, Debug)]
+pub struct WidgetFocused;
+
+/// Lets a widget take focus. Builders add this to every widget root.
+///
+/// Widgets with a positive `tab_index` come first, in ascending order, then
+/// those with `0` in reading order (top to bottom, left to right). A negative
+/// index can only be focused by clicking or from code.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Focusable {
+    pub tab_index: i32,
+}
+
+impl Focusable {
+    pub fn with_tab_index(tab_index: i32) -> Self {
+        Self { tab_index }
+    }
+}
+
+/// Where the focus ring goes when this widget is focused, e.g. a slider's
+/// handle. Without it the ring surrounds the focusable entity itself.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct FocusRingTarget(pub Entity);
+
+/// Outline node spawned as a child of the ring target.
+#[derive(Component, Debug)]
+pub struct FocusRing {
+    pub target: Entity,
+}
+
+/// Confines Tab traversal to this entity's descendants while it exists, for
+/// modal panels. Focus moves to the first focusable inside when the scope is
+/// added and goes back to where it was when the scope is removed.
+#[derive(Component, Debug, Default)]
+pub struct FocusScope;
+
+/// Looks up focusable widgets and their traversal order.
+#[derive(SystemParam)]
+pub struct FocusTree<'w, 's> {
+    focusables: Query<
+        'w,
+        's,
+        (Entity, &'static Focusable, Option<&'static GlobalTransform>, Option<&'static ComputedNode>),
+    >,
+    parents: Query<'w, 's, &'static ChildOf>,
+    visibility: Query<'w, 's, &'static Visibility>,
+}
+
+impl FocusTree<'_, '_> {
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.focusables.contains(entity)
+    }
+
+    pub fn is_inside(&self, entity: Entity, scope: Entity) -> bool {
+        entity == scope || self.parents.iter_ancestors(entity).any(|ancestor| ancestor == scope)
+    }
+
+    /// `entity` itself if it is focusable, else its nearest focusable ancestor.
+    pub fn focusable_ancestor(&self, entity: Entity) -> Option<Entity> {
+        std::iter::once(entity)
+            .chain(self.parents.iter_ancestors(entity))
+            .find(|entity| self.focusables.contains(*entity))
+    }
+
+    fn is_hidden(&self, entity: Entity) -> bool {
+        std::iter::once(entity)
+            .chain(self.parents.iter_ancestors(entity))
+            .any(|entity| self.visibility.get(entity) == Ok(&Visibility::Hidden))
+    }
+
+    /// Visible widgets Tab can reach inside `scope`, or anywhere, in order.
+    ///
+    /// Widgets whose centers fall within the first widget of a row's height
+    /// share that row, so slightly misaligned widgets still go left to right.
+    pub fn tab_order(&self, scope: Option<Entity>) -> Vec<Entity> {
+        let mut entries: Vec<(i32, Vec3, f32, Entity)> = self
+            .focusables
+            .iter()
+            .filter(|(entity, focusable, ..)| {
+                focusable.tab_index >= 0
+                    && scope.is_none_or(|scope| self.is_inside(*entity, scope))
+                    && !self.is_hidden(*entity)
+            })
+            .map(|(entity, focusable, transform, node)| {
+                let position = transform.map_or(Vec3::ZERO, GlobalTransform::translation);
+                let half_height = node.map_or(0.0, |node| node.size().y / 2.0);
+                (focusable.tab_index, position, half_height, entity)
+            })
+            .collect();
+
+        let group = |tab_index: i32| if tab_index > 0 { tab_index } else { i32::MAX };
+        entries.sort_by(|a, b| {
+            group(a.0)
+                .cmp(&group(b.0))
+                .then(a.1.y.total_cmp(&b.1.y))
+                .then(a.1.x.total_cmp(&b.1.x))
+                .then(a.3.cmp(&b.3))
+        });
+
+        let mut rows = Vec::with_capacity(entries.len());
+        let mut row = 0;
+        let mut row_bottom = f32::NEG_INFINITY;
+        for (index, (tab_index, position, half_height, _)) in entries.iter().enumerate() {
+            let new_group = index > 0 && group(entries[index - 1].0) != group(*tab_index);
+            if new_group || position.y > row_bottom {
+                row += 1;
+                row_bottom = position.y + half_height;
+            }
+            rows.push(row);
+        }
+
+        let mut ordered: Vec<(usize, f32, Entity)> = entries
+            .iter()
+            .zip(rows)
+            .map(|((_, position, _, entity), row)| (row, position.x, *entity))
+            .collect();
+        ordered.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)).then(a.2.cmp(&b.2)));
+        ordered.into_iter().map(|(_, _, entity)| entity).collect()
+    }
+}
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct WidgetFocusSystem;
+
+/// Owns `WidgetFocus` and draws the focus ring. Widget plugins run their input
+/// set after `WidgetFocusSystem`.
+pub struct WidgetFocusPlugin;
+
+impl Plugin for WidgetFocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin_once(WidgetInputPlugin);
+        app.add_plugin_once(WidgetThemePlugin);
+
+        app.init_resource::<WidgetFocus>().add_systems(
+            Update,
+            (
+                focus_modality_system,
+                focus_scope_system,
+                focus_click_system,
+                focus_navigation_system,
+                focus_marker_system,
+                focus_ring_system,
+            )
+                .chain()
+                .in_set(WidgetFocusSystem),
+        );
+    }
+}
+
+pub fn focus_modality_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut focus: ResMut<WidgetFocus>,
+) {
+    let visible = if mouse.get_just_pressed().next().is_some() {
+        false
+    } else if keys.get_just_pressed().next().is_some() {
+        true
+    } else {
+        return;
+    };
+    if focus.visible != visible {
+        focus.visible = visible;
+    }
+}
+
+pub fn focus_scope_system(
+    mut focus: ResMut<WidgetFocus>,
+    q_new_scopes: Query<Entity, Added<FocusScope>>,
+    mut removed_scopes: RemovedComponents<FocusScope>,
+    tree: FocusTree,
+) {
+    for scope in removed_scopes.read() {
+        let Some(index) = focus.scopes.iter().position(|(open, _)| *open == scope) else {
+            continue;
+        };
+        let (_, restore) = focus.scopes.remove(index);
+        let focus_was_inside = focus
+            .focused
+            .is_none_or(|focused| !tree.contains(focused) || tree.is_inside(focused, scope));
+        if focus_was_inside {
+            focus.focused = restore.filter(|entity| tree.contains(*entity));
+        }
+    }
+
+    for scope in &q_new_scopes {
+        let restore = focus.focused;
+        focus.scopes.push((scope, restore));
+        focus.focused = tree.tab_order(Some(scope)).first().copied();
+    }
+}
+
+pub fn focus_click_system(
+    mut focus: ResMut<WidgetFocus>,
+    q_interactions: Query<(Entity, &Interaction), Changed<Interaction>>,
+    tree: FocusTree,
+) {
+    for (entity, interaction) in &q_interactions {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        // Clicks behind a modal scope, e.g. on a backdrop, don't take focus out of it
+        if focus.active_scope().is_some_and(|scope| !tree.is_inside(entity, scope)) {
+            continue;
+        }
+        if let Some(target) = tree.focusable_ancestor(entity) {
+            if !focus.is_focused(target) {
+                focus.set(target);
+            }
+        }
+    }
+}
+
+pub fn focus_navigation_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<WidgetFocus>,
+    tree: FocusTree,
+) {
+    if !keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+
+    let order = tree.tab_order(focus.active_scope());
+    let len = order.len();
+    if len == 0 {
+        return;
+    }
+
+    let reverse = keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight);
+    let current = focus.focused.and_then(|focused| order.iter().position(|entity| *entity == focused));
+    let next = match current {
+        None if reverse => len - 1,
+        None => 0,
+        Some(index) if reverse => (index + len - 1) % len,
+        Some(index) => (index + 1) % len,
+    };
+    focus.set(order[next]);
+}
+
+/// Moves `WidgetFocused` to the focused entity, and drops focus from
+/// widgets that were despawned.
+pub fn focus_marker_system(
+    mut commands: Commands,
+    mut focus: ResMut<WidgetFocus>,
+    tree: FocusTree,
+    q_marked: Query<Entity, With<WidgetFocused>>,
+) {
+    if focus.focused.is_some_and(|focused| !tree.contains(focused)) {
+        focus.clear();
+    }
+
+    for entity in &q_marked {
+        if !focus.is_focused(entity) {
+            commands.entity(entity).remove::<WidgetFocused>();
+        }
+    }
+    if let Some(focused) = focus.focused {
+        if !q_marked.contains(focused) {
+            commands.entity(focused).insert(WidgetFocused);
+        }
+    }
+}
+
+/// Keeps one `FocusRing` around the focused widget while focus is visible,
+/// restyling it when the theme changes.
+pub fn focus_ring_system(
+    mut commands: Commands,
+    focus: Res<WidgetFocus>,
+    themes: ThemeLookup,
+    q_targets: Query<&FocusRingTarget>,
+    mut q_rings: Query<(Entity, &FocusRing, &mut Node, &mut BorderColor, &mut BorderRadius)>,
+) {
+    let target = focus
+        .get()
+        .filter(|_| focus.is_visible())
+        .map(|focused| q_targets.get(focused).map_or(focused, |target| target.0));
+
+    let mut has_ring = false;
+    for (ring_entity, ring, mut node, mut color, mut radius) in &mut q_rings {
+        if Some(ring.target) != target {
+            commands.entity(ring_entity).despawn();
+            continue;
+        }
+        has_ring = true;
+        if themes.changed() {
+            let style = &themes.get(ring.target).focus_ring;
+            *node = focus_ring_node(style);
+            color.0 = style.color;
+            *radius = BorderRadius::all(Val::Px(style.radius));
+        }
+    }
+
+    if let (Some(target), false) = (target, has_ring) {
+        let style = &themes.get(target).focus_ring;
+        commands.spawn((
+            focus_ring_node(style),
+            BorderColor(style.color),
+            BorderRadius::all(Val::Px(style.radius)),
+            FocusPolicy::Pass,
+            FocusRing { target },
+            ChildOf(target),
+        ));
+    }
+}
+
+fn focus_ring_node(style: &FocusRingStyle) -> Node {
+    // Stretched past every edge of the target by the offset plus the ring itself
+    let inset = Val::Px(-(style.offset + style.width));
+    Node {
+        position_type: PositionType::Absolute,
+        left: inset,
+        right: inset,
+        top: inset,
+        bottom: inset,
+        border: UiRect::all(Val::Px(style.width)),
+        ..default()
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::MouseWheel;
+use bevy::prelude::*;
+use bevy::ui::UiScale;
+use bevy::window::CursorLeft;
+
+/// Cursor position shared by every widget, in logical window pixels.
+///
+/// Tracked from `CursorMoved`/`CursorLeft`, so it works with any window setup
+/// and can be driven by synthetic events in headless apps.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct WidgetCursor {
+    pub position: Option<Vec2>,
+}
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct WidgetInputSystem;
+
+/// Cursor tracking plus the input resources and events the widget systems
+/// read.
+pub struct WidgetInputPlugin;
+
+impl Plugin for WidgetInputPlugin {
+    fn build(&self, app: &mut App) {
+        // No-ops when InputPlugin/WindowPlugin/UiPlugin are present, and keeps
+        // the widgets usable from a blank `App` when they aren't
+        app
+            .init_resource::<WidgetCursor>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<UiScale>()
+            .init_resource::<Time>()
+            .add_event::<CursorMoved>()
+            .add_event::<CursorLeft>()
+            .add_event::<MouseWheel>()
+            .add_event::<KeyboardInput>()
+            .configure_sets(PreUpdate, WidgetInputSystem.after(bevy::input::InputSystem))
+            .add_systems(PreUpdate, widget_cursor_tracking_system.in_set(WidgetInputSystem));
+    }
+}
+
+pub fn widget_cursor_tracking_system(
+    mut cursor: ResMut<WidgetCursor>,
+    mut evr_moved: EventReader<CursorMoved>,
+    mut evr_left: EventReader<CursorLeft>,
+) {
+    if let Some(moved) = evr_moved.read().last() {
+        cursor.position = Some(moved.position);
+    } else if evr_left.read().last().is_some() {
+        cursor.position = None;
+    }
+    evr_left.clear();
+}
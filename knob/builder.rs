@@ -0,0 +1,208 @@
+use bevy::prelude::*;
+use bevy::ui::*;
+use super::components::*;
+use super::systems::point_on_dial;
+use super::super::slider::components::*;
+use crate::focus::{FocusRingTarget, Focusable};
+use crate::theme::{WidgetTheme, WidgetThemeOverride};
+use std::sync::Arc;
+
+pub struct KnobBuilder<'w, 's, 'a> {
+    commands: &'a mut Commands<'w, 's>,
+    bundle: KnobBundle,
+    segment_count: usize,
+    text_style: TextFont,
+    theme: Option<WidgetTheme>,
+    focusable: Focusable,
+}
+
+impl<'w, 's, 'a> KnobBuilder<'w, 's, 'a> {
+    pub fn new(commands: &'a mut Commands<'w, 's>) -> Self {
+        Self {
+            commands,
+            bundle: KnobBundle::default(),
+            segment_count: 24,
+            text_style: TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            theme: None,
+            focusable: Focusable::default(),
+        }
+    }
+
+    pub fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.bundle.slider.min = min;
+        self.bundle.slider.max = max;
+        self.bundle.slider.value = min;
+        self
+    }
+
+    pub fn with_value(mut self, value: f32) -> Self {
+        self.bundle.slider.value = value.clamp(self.bundle.slider.min, self.bundle.slider.max);
+        self
+    }
+
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.bundle.slider.step = Some(step);
+        self
+    }
+
+    pub fn with_scale(mut self, scale: SliderScale) -> Self {
+        self.bundle.slider.scale = scale;
+        self
+    }
+
+    /// Start and end of the sweep in degrees, clockwise from 12 o'clock.
+    pub fn with_angles(mut self, start_angle: f32, end_angle: f32) -> Self {
+        self.bundle.knob.start_angle = start_angle;
+        self.bundle.knob.end_angle = end_angle;
+        self
+    }
+
+    pub fn with_drag_mode(mut self, drag_mode: KnobDragMode) -> Self {
+        self.bundle.knob.drag_mode = drag_mode;
+        self
+    }
+
+    /// Colors in `style` that differ from the defaults win over the theme.
+    pub fn with_style(mut self, style: KnobStyle) -> Self {
+        self.bundle.style = style;
+        self
+    }
+
+    pub fn with_segments(mut self, count: usize) -> Self {
+        self.segment_count = count.max(2);
+        self
+    }
+
+    pub fn with_emit_mode(mut self, emit_mode: SliderEmitMode) -> Self {
+        self.bundle.emit_mode = emit_mode;
+        self
+    }
+
+    pub fn with_value_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(f32) -> String + Send + Sync + 'static,
+    {
+        self.bundle.options.format = ValueFormat::Custom(Arc::new(formatter));
+        self
+    }
+
+    /// Position in Tab order; see `Focusable`.
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.focusable = Focusable::with_tab_index(tab_index);
+        self
+    }
+
+    /// Styles this knob with `theme` instead of the global `WidgetTheme`.
+    pub fn with_theme(mut self, theme: WidgetTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    pub fn spawn(self) -> Entity {
+        self.spawn_internal().1
+    }
+
+    pub fn spawn_with_parent(self, parent_entity: Entity) -> Entity {
+        let (commands, knob_entity) = self.spawn_internal();
+        commands.entity(parent_entity).add_children(&[knob_entity]);
+        knob_entity
+    }
+
+    fn spawn_internal(self) -> (&'a mut Commands<'w, 's>, Entity) {
+        let style = self.bundle.style.clone();
+        let knob = self.bundle.knob.clone();
+
+        let dial_entity = self.commands.spawn((
+            Node {
+                width: Val::Px(style.size),
+                height: Val::Px(style.size),
+                ..default()
+            },
+            BorderRadius::all(Val::Percent(50.0)),
+            BackgroundColor(style.dial_color),
+            KnobDial,
+            Interaction::None,
+        )).id();
+
+        // The arc is drawn as a ring of dots that light up as the value grows
+        let ring_radius = (style.size - style.segment_size) / 2.0;
+        let last = (self.segment_count - 1) as f32;
+        let segments: Vec<Entity> = (0..self.segment_count)
+            .map(|i| {
+                let position = i as f32 / last;
+                let offset = point_on_dial(
+                    knob.angle_for_position(position),
+                    ring_radius,
+                    style.size,
+                    style.segment_size,
+                );
+                self.commands.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(offset.x),
+                        top: Val::Px(offset.y),
+                        width: Val::Px(style.segment_size),
+                        height: Val::Px(style.segment_size),
+                        ..default()
+                    },
+                    BorderRadius::all(Val::Percent(50.0)),
+                    BackgroundColor(style.track_color),
+                    FocusPolicy::Pass,
+                    KnobArcSegment { position },
+                )).id()
+            })
+            .collect();
+
+        let indicator_entity = self.commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(style.indicator_size),
+                height: Val::Px(style.indicator_size),
+                ..default()
+            },
+            BorderRadius::all(Val::Percent(50.0)),
+            BackgroundColor(style.indicator_color),
+            FocusPolicy::Pass,
+            KnobIndicator,
+        )).id();
+
+        let text_entity = self.commands.spawn((
+            Text::new(""),
+            self.text_style,
+            KnobValueText,
+        )).id();
+
+        let explicit_colors = self.bundle.style.explicit_colors();
+        let knob_entity = self.commands.spawn((
+            KnobBundle {
+                slider: Slider {
+                    handle_entity: dial_entity,
+                    ..self.bundle.slider
+                },
+                ..self.bundle
+            },
+            KnobParts {
+                dial: dial_entity,
+                indicator: indicator_entity,
+                segments: segments.clone(),
+                text: text_entity,
+            },
+            explicit_colors,
+            self.focusable,
+            FocusRingTarget(dial_entity),
+        )).id();
+
+        self.commands.entity(dial_entity).add_children(&segments);
+        self.commands.entity(dial_entity).add_child(indicator_entity);
+        self.commands.entity(knob_entity).add_children(&[dial_entity, text_entity]);
+
+        if let Some(theme) = self.theme {
+            self.commands.entity(knob_entity).insert(WidgetThemeOverride(theme));
+        }
+
+        (self.commands, knob_entity)
+    }
+}
@@ -0,0 +1,177 @@
+use bevy::prelude::*;
+use bevy::ui::*;
+use super::super::slider::components::*;
+use crate::theme::ExplicitColors;
+
+#[derive(Component, Debug, Clone)]
+pub struct Knob {
+    /// Angle of the minimum value in degrees, clockwise from 12 o'clock.
+    pub start_angle: f32,
+    /// Angle of the maximum value in degrees, clockwise from 12 o'clock.
+    pub end_angle: f32,
+    pub drag_mode: KnobDragMode,
+}
+
+impl Default for Knob {
+    fn default() -> Self {
+        Self {
+            start_angle: -135.0,
+            end_angle: 135.0,
+            drag_mode: KnobDragMode::default(),
+        }
+    }
+}
+
+impl Knob {
+    pub fn sweep(&self) -> f32 {
+        self.end_angle - self.start_angle
+    }
+
+    pub fn angle_for_position(&self, position: f32) -> f32 {
+        self.start_angle + self.sweep() * position.clamp(0.0, 1.0)
+    }
+
+    /// Maps an angle back onto the sweep. Angles in the dead zone outside the
+    /// sweep snap to whichever end is closer.
+    pub fn position_for_angle(&self, angle: f32) -> f32 {
+        let sweep = self.sweep().abs().clamp(f32::EPSILON, 360.0);
+        let direction = self.sweep().signum();
+        let relative = ((angle - self.start_angle) * direction).rem_euclid(360.0);
+        if relative <= sweep {
+            relative / sweep
+        } else if relative - sweep < 360.0 - relative {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KnobDragMode {
+    /// Drag up to increase, down to decrease, like most audio software.
+    Vertical { pixels_per_range: f32 },
+    /// The value follows the cursor's angle around the knob's center.
+    Angular,
+}
+
+impl Default for KnobDragMode {
+    fn default() -> Self {
+        KnobDragMode::Vertical { pixels_per_range: 200.0 }
+    }
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct KnobParts {
+    pub dial: Entity,
+    pub indicator: Entity,
+    pub segments: Vec<Entity>,
+    pub text: Entity,
+}
+
+#[derive(Component, Default)]
+pub struct KnobDragState {
+    pub is_dragging: bool,
+    pub drag_start_position: Vec2,
+    pub drag_start_progress: f32,
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct KnobStyle {
+    pub size: f32,
+    pub segment_size: f32,
+    pub indicator_size: f32,
+    pub dial_color: Color,
+    pub track_color: Color,
+    pub fill_color: Color,
+    pub indicator_color: Color,
+}
+
+impl Default for KnobStyle {
+    fn default() -> Self {
+        Self {
+            size: 64.0,
+            segment_size: 5.0,
+            indicator_size: 8.0,
+            dial_color: Color::srgb(0.2, 0.2, 0.2),
+            track_color: Color::srgb(0.35, 0.35, 0.35),
+            fill_color: Color::srgb(0.0, 0.0, 1.0),
+            indicator_color: Color::WHITE,
+        }
+    }
+}
+
+impl KnobStyle {
+    /// The dial, track, fill and indicator colors that differ from the
+    /// defaults, in that order.
+    pub(crate) fn explicit_colors(&self) -> ExplicitColors {
+        let default = Self::default();
+        ExplicitColors::from_defaults(&[
+            (self.dial_color, default.dial_color),
+            (self.track_color, default.track_color),
+            (self.fill_color, default.fill_color),
+            (self.indicator_color, default.indicator_color),
+        ])
+    }
+}
+
+#[derive(Component)]
+pub struct KnobDial;
+
+#[derive(Component)]
+pub struct KnobIndicator;
+
+/// One dot of the arc; `position` is where it sits along the sweep (0..=1).
+#[derive(Component)]
+pub struct KnobArcSegment {
+    pub position: f32,
+}
+
+#[derive(Component)]
+pub struct KnobValueText;
+
+#[derive(Bundle)]
+pub struct KnobBundle {
+    pub knob: Knob,
+    pub style: KnobStyle,
+    pub slider: Slider,
+    pub options: SliderOptions,
+    pub emit_mode: SliderEmitMode,
+    pub key_repeat: SliderKeyRepeat,
+    pub drag_state: KnobDragState,
+    pub node: Node,
+    pub focus_policy: FocusPolicy,
+}
+
+impl Default for KnobBundle {
+    fn default() -> Self {
+        Self {
+            knob: Knob::default(),
+            style: KnobStyle::default(),
+            slider: Slider {
+                min: 0.0,
+                max: 1.0,
+                step: None,
+                orientation: SliderOrientation::Vertical,
+                value: 0.5,
+                page_step: None,
+                scale: SliderScale::Linear,
+                handle_entity: Entity::PLACEHOLDER,
+            },
+            options: SliderOptions {
+                format: ValueFormat::default(),
+                show_value: true,
+                parser: None,
+            },
+            emit_mode: SliderEmitMode::default(),
+            key_repeat: SliderKeyRepeat::default(),
+            drag_state: KnobDragState::default(),
+            node: Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            focus_policy: FocusPolicy::Block,
+        }
+    }
+}
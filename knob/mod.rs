@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod systems;
+pub mod builder;
+
+pub use components::*;
+pub use systems::*;
+pub use builder::KnobBuilder;
+
+use super::slider::{SliderPlugin, SliderSystem};
+use crate::WidgetAppExt;
+
+/// Rotary knob built on the slider model. Knobs carry a `Slider`, so they
+/// share its keyboard handling, emit modes and `SliderValueChangedEvent`.
+pub struct KnobPlugin;
+
+impl Plugin for KnobPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin_once(SliderPlugin);
+
+        app
+            .add_systems(Update, (
+                knob_drag_system
+                    .in_set(SliderSystem::ProcessInput)
+                    .before(super::slider::slider_buffer_changes_system),
+                knob_theme_system
+                    .in_set(SliderSystem::UpdateVisuals)
+                    .before(knob_update_visuals_system),
+                knob_update_visuals_system.in_set(SliderSystem::UpdateVisuals),
+            ));
+    }
+}
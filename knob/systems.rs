@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+use bevy::ui::{ComputedNode, UiScale};
+use crate::input::WidgetCursor;
+use crate::theme::{ExplicitColors, ThemeLookup};
+use super::components::*;
+use super::super::slider::{
+    apply_step, track_geometry,
+    components::*,
+    events::{SliderChangeKind, SliderValueChangedEvent},
+};
+
+pub fn knob_drag_system(
+    mut commands: Commands,
+    mut q_knobs: Query<(
+        Entity,
+        &mut Slider,
+        &Knob,
+        &KnobParts,
+        &mut KnobDragState,
+        &SliderEmitMode,
+    )>,
+    q_dials: Query<(&Interaction, &ComputedNode, &GlobalTransform), With<KnobDial>>,
+    mut evw_slider_change: EventWriter<SliderValueChangedEvent>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    ui_scale: Res<UiScale>,
+    cursor: Res<WidgetCursor>,
+) {
+    let cursor_position = cursor.position;
+
+    for (knob_entity, mut slider, knob, parts, mut drag, emit_mode) in &mut q_knobs {
+        let Ok((interaction, dial_node, dial_transform)) = q_dials.get(parts.dial) else {
+            continue;
+        };
+
+        if !mouse_input.pressed(MouseButton::Left) {
+            drag.is_dragging = false;
+            continue;
+        }
+        let Some(cursor_pos) = cursor_position else {
+            continue;
+        };
+
+        if *interaction == Interaction::Pressed && !drag.is_dragging {
+            drag.is_dragging = true;
+            drag.drag_start_position = cursor_pos;
+            drag.drag_start_progress = slider.scale.position_from_value(slider.value, slider.min, slider.max);
+        }
+        if !drag.is_dragging {
+            continue;
+        }
+
+        let position = match knob.drag_mode {
+            KnobDragMode::Vertical { pixels_per_range } => {
+                // Cursor y grows downwards, so dragging up raises the value
+                drag.drag_start_progress
+                    + (drag.drag_start_position.y - cursor_pos.y) / pixels_per_range.max(1.0)
+            }
+            KnobDragMode::Angular => {
+                let (center, _) = track_geometry(
+                    dial_node.size(),
+                    dial_transform.translation().truncate(),
+                    dial_node.inverse_scale_factor(),
+                    ui_scale.0,
+                );
+                let offset = cursor_pos - center;
+                knob.position_for_angle(offset.x.atan2(-offset.y).to_degrees())
+            }
+        };
+
+        let new_value = apply_step(
+            slider.scale.value_from_position(position, slider.min, slider.max),
+            slider.min,
+            slider.step,
+        ).clamp(slider.min, slider.max);
+
+        if (new_value - slider.value).abs() > f32::EPSILON {
+            let previous_value = slider.value;
+            slider.value = new_value;
+
+            commands.entity(knob_entity).insert(SliderNeedsVisualUpdate);
+
+            evw_slider_change.write(SliderValueChangedEvent {
+                entity: knob_entity,
+                handle_entity: parts.dial,
+                previous_value,
+                new_value,
+                orientation: slider.orientation,
+                preview: *emit_mode == SliderEmitMode::OnRelease,
+                kind: SliderChangeKind::User,
+            });
+        }
+    }
+}
+
+pub fn knob_update_visuals_system(
+    q_knobs: Query<
+        (&Slider, &SliderOptions, &Knob, &KnobStyle, &KnobParts),
+        Or<(Changed<Slider>, Changed<Knob>, Changed<KnobStyle>)>
+    >,
+    mut q_segments: Query<(&KnobArcSegment, &mut BackgroundColor)>,
+    mut q_indicators: Query<&mut Node, With<KnobIndicator>>,
+    mut q_text: Query<&mut Text, With<KnobValueText>>,
+) {
+    for (slider, options, knob, style, parts) in &q_knobs {
+        let position = slider.scale.position_from_value(slider.value, slider.min, slider.max);
+
+        for &segment_entity in &parts.segments {
+            if let Ok((segment, mut color)) = q_segments.get_mut(segment_entity) {
+                color.0 = if segment.position <= position + f32::EPSILON {
+                    style.fill_color
+                } else {
+                    style.track_color
+                };
+            }
+        }
+
+        if let Ok(mut indicator_node) = q_indicators.get_mut(parts.indicator) {
+            let radius = style.size * 0.3;
+            let offset = point_on_dial(knob.angle_for_position(position), radius, style.size, style.indicator_size);
+            indicator_node.left = Val::Px(offset.x);
+            indicator_node.top = Val::Px(offset.y);
+        }
+
+        if let Ok(mut text) = q_text.get_mut(parts.text) {
+            **text = if options.show_value {
+                options.format.format(slider.value)
+            } else {
+                String::new()
+            };
+        }
+    }
+}
+
+/// Copies theme colors the builder wasn't given into each knob's style, on
+/// spawn and whenever the theme changes. The arc picks them up in
+/// `knob_update_visuals_system`.
+pub fn knob_theme_system(
+    themes: ThemeLookup,
+    mut q_knobs: Query<(Entity, Ref<Knob>, &mut KnobStyle, Option<&ExplicitColors>, &KnobParts)>,
+    mut q_parts: Query<&mut BackgroundColor, Or<(With<KnobDial>, With<KnobIndicator>)>>,
+    mut q_text: Query<(&mut TextColor, &mut TextFont), With<KnobValueText>>,
+) {
+    let restyle_all = themes.changed();
+
+    for (entity, knob, mut style, explicit, parts) in &mut q_knobs {
+        if !restyle_all && !knob.is_added() {
+            continue;
+        }
+        let theme = themes.get(entity);
+        let explicit = explicit.copied().unwrap_or_default();
+        explicit.apply(0, &mut style.dial_color, theme.palette.surface);
+        explicit.apply(1, &mut style.track_color, theme.palette.track);
+        explicit.apply(2, &mut style.fill_color, theme.palette.accent);
+        explicit.apply(3, &mut style.indicator_color, theme.palette.handle);
+
+        if let Ok(mut color) = q_parts.get_mut(parts.dial) {
+            color.0 = style.dial_color;
+        }
+        if let Ok(mut color) = q_parts.get_mut(parts.indicator) {
+            color.0 = style.indicator_color;
+        }
+        if let Ok((mut color, mut font)) = q_text.get_mut(parts.text) {
+            color.0 = theme.palette.text;
+            *font = theme.typography.text_font(theme.typography.body_size);
+        }
+    }
+}
+
+/// Top-left offset for a square element of `element_size` centered on the
+/// point `radius` away from the dial's center at `angle` degrees.
+pub(crate) fn point_on_dial(angle: f32, radius: f32, dial_size: f32, element_size: f32) -> Vec2 {
+    let angle = angle.to_radians();
+    let center = dial_size / 2.0;
+    Vec2::new(
+        center + radius * angle.sin() - element_size / 2.0,
+        center - radius * angle.cos() - element_size / 2.0,
+    )
+}
@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+use bevy::ui::widget::Label;
+
+use crate::focus::{focus_click_system, focus_marker_system, WidgetFocus, WidgetFocusPlugin, WidgetFocusSystem};
+use crate::theme::{ThemeLookup, WidgetThemePlugin};
+use crate::WidgetAppExt;
+
+/// Where a control's label and description sit relative to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum LabelPosition {
+    Left,
+    #[default]
+    Right,
+    Above,
+    Below,
+}
+
+/// Text naming a control. Clicking it focuses the control, and toggles
+/// toggles and checkboxes like clicking them would.
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct WidgetLabel {
+    pub control: Entity,
+}
+
+/// Smaller text explaining a control, shown under its label.
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct WidgetDescription {
+    pub control: Entity,
+}
+
+/// Added to a control spawned with a label or description.
+///
+/// The builder wraps the control and its texts in `container`, so place
+/// labelled controls with `spawn_with_parent`, which adds the container:
+///
+/// ```ignore
+/// let toggle = ToggleBuilder::new(&mut commands)
+///     .with_label("Notifications", LabelPosition::Left)
+///     .spawn_with_parent(settings_panel);
+/// ```
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct WidgetLabels {
+    pub container: Entity,
+    pub label: Option<Entity>,
+    pub description: Option<Entity>,
+}
+
+/// What `with_label` and `with_description` collected on a builder.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LabelText {
+    label: Option<String>,
+    description: Option<String>,
+    position: LabelPosition,
+}
+
+impl LabelText {
+    pub(crate) fn set_label(&mut self, text: impl Into<String>, position: LabelPosition) {
+        self.label = Some(text.into());
+        self.position = position;
+    }
+
+    pub(crate) fn set_description(&mut self, text: impl Into<String>) {
+        self.description = Some(text.into());
+    }
+
+    /// Wraps `control` in a container with the label and description, if
+    /// there are any, and returns the container.
+    pub(crate) fn spawn(self, commands: &mut Commands, control: Entity) -> Option<Entity> {
+        if self.label.is_none() && self.description.is_none() {
+            return None;
+        }
+
+        let label = self.label.map(|text| {
+            commands.spawn((Text::new(text), Label, Interaction::None, WidgetLabel { control })).id()
+        });
+        let description = self.description.map(|text| {
+            commands.spawn((Text::new(text), Label, WidgetDescription { control })).id()
+        });
+
+        let text_block = commands.spawn(Node {
+            flex_direction: FlexDirection::Column,
+            ..default()
+        }).id();
+        commands.entity(text_block).add_children(&label.into_iter().chain(description).collect::<Vec<_>>());
+
+        let (flex_direction, children) = match self.position {
+            LabelPosition::Left => (FlexDirection::Row, [text_block, control]),
+            LabelPosition::Right => (FlexDirection::Row, [control, text_block]),
+            LabelPosition::Above => (FlexDirection::Column, [text_block, control]),
+            LabelPosition::Below => (FlexDirection::Column, [control, text_block]),
+        };
+        let container = commands.spawn(Node {
+            flex_direction,
+            align_items: if flex_direction == FlexDirection::Row { AlignItems::Center } else { AlignItems::FlexStart },
+            ..default()
+        }).id();
+        commands.entity(container).add_children(&children);
+
+        commands.entity(control).insert(WidgetLabels { container, label, description });
+        Some(container)
+    }
+}
+
+/// Focuses controls through their labels and styles label text.
+pub struct WidgetLabelPlugin;
+
+impl Plugin for WidgetLabelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin_once(WidgetFocusPlugin);
+        app.add_plugin_once(WidgetThemePlugin);
+
+        app.register_type::<LabelPosition>()
+            .add_systems(
+                Update,
+                label_focus_system
+                    .in_set(WidgetFocusSystem)
+                    .after(focus_click_system)
+                    .before(focus_marker_system),
+            )
+            .add_systems(Update, label_theme_system);
+    }
+}
+
+/// Pressing a label focuses its control, as clicking the control would.
+pub fn label_focus_system(
+    mut focus: ResMut<WidgetFocus>,
+    q_labels: Query<(&Interaction, &WidgetLabel), Changed<Interaction>>,
+) {
+    for (interaction, label) in &q_labels {
+        if *interaction == Interaction::Pressed && !focus.is_focused(label.control) {
+            focus.set(label.control);
+        }
+    }
+}
+
+/// Applies theme text styles to labels, descriptions and the gaps around
+/// them, on spawn and whenever the theme changes.
+pub fn label_theme_system(
+    themes: ThemeLookup,
+    q_controls: Query<(Entity, Ref<WidgetLabels>)>,
+    mut q_containers: Query<&mut Node>,
+    mut q_texts: Query<(&mut TextColor, &mut TextFont)>,
+) {
+    let restyle_all = themes.changed();
+
+    for (control, labels) in &q_controls {
+        if !restyle_all && !labels.is_added() {
+            continue;
+        }
+        // Overrides live on the control, inside the container
+        let theme = themes.get(control);
+        if let Ok(mut node) = q_containers.get_mut(labels.container) {
+            node.column_gap = Val::Px(theme.spacing.padding);
+            node.row_gap = Val::Px(theme.spacing.gap);
+        }
+        if let Some(Ok((mut color, mut font))) = labels.label.map(|label| q_texts.get_mut(label)) {
+            color.0 = theme.palette.text;
+            *font = theme.typography.text_font(theme.typography.body_size);
+        }
+        if let Some(Ok((mut color, mut font))) = labels.description.map(|description| q_texts.get_mut(description)) {
+            color.0 = theme.palette.text.with_alpha(0.7);
+            *font = theme.typography.text_font(theme.typography.label_size);
+        }
+    }
+}
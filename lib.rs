@@ -0,0 +1,79 @@
+use bevy::app::PluginGroupBuilder;
+use bevy::prelude::*;
+
+mod accessibility;
+pub mod anim;
+pub mod binding;
+pub mod focus;
+pub mod input;
+pub mod label;
+pub mod motion;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod theme;
+pub mod dropdown;
+pub mod knob;
+pub mod progress_bar;
+pub mod radio_group;
+pub mod slider;
+pub mod stylesheet;
+pub mod tab_group;
+pub mod toggle;
+
+pub use anim::{AnimatedValue, EasingFunction, Spring, Transition, Tween};
+pub use binding::{BindableWidget, Lens, WidgetBinding, WidgetBindingPlugin};
+pub use focus::{FocusRing, FocusRingTarget, FocusScope, Focusable, WidgetFocus, WidgetFocusPlugin, WidgetFocused};
+pub use input::{WidgetCursor, WidgetInputPlugin};
+pub use label::{LabelPosition, WidgetDescription, WidgetLabel, WidgetLabelPlugin, WidgetLabels};
+pub use motion::{WidgetMotionPlugin, WidgetMotionSettings};
+pub use stylesheet::{ActiveWidgetStyleSheet, WidgetStyleSheet, WidgetStyleSheetPlugin};
+pub use theme::{FocusRingStyle, WidgetTheme, WidgetThemeOverride, WidgetThemePlugin};
+
+/// Each widget plugin adds the shared plugins it needs (input, focus, theme,
+/// binding, motion, labels) through `add_plugin_once`, so any one of them works
+/// without `WidgetsPlugin`. The shared plugins only need adding by hand to
+/// order other systems against their sets.
+pub(crate) trait WidgetAppExt {
+    /// Adds `plugin` unless the app already has one of its type.
+    fn add_plugin_once<P: Plugin>(&mut self, plugin: P) -> &mut Self;
+}
+
+impl WidgetAppExt for App {
+    fn add_plugin_once<P: Plugin>(&mut self, plugin: P) -> &mut Self {
+        if !self.is_plugin_added::<P>() {
+            self.add_plugins(plugin);
+        }
+        self
+    }
+}
+
+/// Registers every widget plugin.
+///
+/// ```ignore
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugins(bevy_widgets::WidgetsPlugin)
+///     .run();
+/// ```
+pub struct WidgetsPlugin;
+
+impl PluginGroup for WidgetsPlugin {
+    fn build(self) -> PluginGroupBuilder {
+        // Shared plugins come first, each after the ones it adds with
+        // `add_plugin_once`, so no widget plugin adds one the group adds later.
+        PluginGroupBuilder::start::<Self>()
+            .add(WidgetInputPlugin)
+            .add(WidgetThemePlugin)
+            .add(WidgetFocusPlugin)
+            .add(WidgetBindingPlugin)
+            .add(WidgetMotionPlugin)
+            .add(WidgetLabelPlugin)
+            .add(toggle::TogglePlugin)
+            .add(slider::SliderPlugin)
+            .add(knob::KnobPlugin)
+            .add(dropdown::DropdownPlugin)
+            .add(tab_group::TabPlugin)
+            .add(progress_bar::ProgressBarPlugin)
+            .add(radio_group::RadioPlugin)
+    }
+}
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+use crate::anim::{EasingFunction, Spring, Transition, Tween};
+
+/// How widgets animate, shared by every widget.
+///
+/// `reduced_motion` makes toggles, dropdowns, tabs and progress bars jump
+/// straight to their end state, for users who asked their system to reduce
+/// motion. Completion events still fire as they would after the animation.
+/// `speed` scales every animation: `2.0` runs them twice as fast, `0.5` at
+/// half speed. A speed that is not a positive finite number also snaps.
+///
+/// ```ignore
+/// fn apply_os_preference(mut motion: ResMut<WidgetMotionSettings>) {
+///     motion.reduced_motion = true;
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct WidgetMotionSettings {
+    pub reduced_motion: bool,
+    pub speed: f32,
+}
+
+impl Default for WidgetMotionSettings {
+    fn default() -> Self {
+        Self {
+            reduced_motion: false,
+            speed: 1.0,
+        }
+    }
+}
+
+impl WidgetMotionSettings {
+    /// Whether animations should skip to their end state this frame.
+    pub fn snaps(&self) -> bool {
+        self.reduced_motion || !(self.speed.is_finite() && self.speed > 0.0)
+    }
+
+    /// Frame time scaled by `speed`, for animations that step every frame.
+    pub fn delta_secs(&self, time: &Time) -> f32 {
+        time.delta_secs() * self.speed
+    }
+}
+
+/// Owns `WidgetMotionSettings` and registers the `anim` types for
+/// reflection.
+pub struct WidgetMotionPlugin;
+
+impl Plugin for WidgetMotionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<EasingFunction>()
+            .register_type::<Tween>()
+            .register_type::<Spring>()
+            .register_type::<Transition>()
+            .init_resource::<WidgetMotionSettings>();
+    }
+}
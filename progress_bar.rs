@@ -1,6 +1,16 @@
+use accesskit::Role;
+use bevy::a11y::AccessibilityNode;
 use bevy::prelude::*;
+use crate::accessibility::sync_accessibility_node;
+use crate::anim::{AnimatedValue, Transition};
+use crate::input::WidgetInputPlugin;
+use crate::motion::{WidgetMotionPlugin, WidgetMotionSettings};
+use crate::theme::{ExplicitColors, ThemeLookup, WidgetTheme, WidgetThemeOverride, WidgetThemePlugin};
+use crate::WidgetAppExt;
 use std::{collections::HashMap, sync::Arc};
 
+pub use crate::anim::EasingFunction;
+
 // =============================================================================
 // BUNDLES
 
@@ -11,6 +21,7 @@ pub struct ProgressBarBundle {
     pub node: Node,
     pub background_color: BackgroundColor,
     pub root: ProgressBarRoot,
+    pub explicit_colors: ExplicitColors,
 }
 
 impl ProgressBarBundle {
@@ -21,6 +32,7 @@ impl ProgressBarBundle {
             node: Node::default(),
             background_color: BackgroundColor::default(),
             root: ProgressBarRoot,
+            explicit_colors: ExplicitColors::default(),
         }
     }
     
@@ -29,7 +41,10 @@ impl ProgressBarBundle {
         self
     }
     
+    /// Sets layout, text and animation options. Colors that differ from the
+    /// defaults win over the theme.
     pub fn with_visuals(mut self, visuals: ProgressBarVisuals) -> Self {
+        self.explicit_colors = visuals.explicit_colors();
         self.visuals = visuals;
         self
     }
@@ -89,11 +104,18 @@ pub struct ProgressBarVisuals {
     pub text_color: Color,
     pub border_width: f32,
     pub fill_margin: f32,
+    /// How the fill moves to a new value
+    pub transition: Transition,
+    /// Seconds. When this or `easing` changes from its default, `transition`
+    /// becomes a tween of this length.
+    #[deprecated(note = "use `transition`")]
     pub animation_duration: f32,
+    #[deprecated(note = "use `transition`")]
     pub easing: EasingFunction,
 }
 
 impl Default for ProgressBarVisuals {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             orientation: ProgressOrientation::Horizontal,
@@ -106,9 +128,38 @@ impl Default for ProgressBarVisuals {
             text_color: Color::WHITE,
             border_width: 2.0,
             fill_margin: 1.0,
-            animation_duration: 0.3, // seconds to complete animation
-            easing: EasingFunction::EaseOut,
+            transition: Transition::tween(DEFAULT_ANIMATION_DURATION, DEFAULT_EASING),
+            animation_duration: DEFAULT_ANIMATION_DURATION,
+            easing: DEFAULT_EASING,
+        }
+    }
+}
+
+/// `ProgressBarVisuals::animation_duration` and `easing` before they were
+/// replaced.
+const DEFAULT_ANIMATION_DURATION: f32 = 0.3;
+const DEFAULT_EASING: EasingFunction = EasingFunction::EaseOut;
+
+impl ProgressBarVisuals {
+    /// `transition`, unless a changed `animation_duration` or `easing` takes over.
+    #[allow(deprecated)]
+    pub(crate) fn resolved_transition(&self) -> Transition {
+        if self.animation_duration != DEFAULT_ANIMATION_DURATION || self.easing != DEFAULT_EASING {
+            return Transition::tween(self.animation_duration, self.easing);
         }
+        self.transition
+    }
+
+    /// The track, fill, border and text colors that differ from the
+    /// defaults, in that order.
+    pub(crate) fn explicit_colors(&self) -> ExplicitColors {
+        let default = Self::default();
+        ExplicitColors::from_defaults(&[
+            (self.track_color, default.track_color),
+            (self.fill_color, default.fill_color),
+            (self.border_color, default.border_color),
+            (self.text_color, default.text_color),
+        ])
     }
 }
 
@@ -125,24 +176,21 @@ impl std::fmt::Debug for ProgressBarVisuals {
             .field("text_color", &self.text_color)
             .field("border_width", &self.border_width)
             .field("fill_margin", &self.fill_margin)
-            .field("animation_duration", &self.animation_duration)
-            .field("easing", &self.easing)
+            .field("transition", &self.transition)
             .finish()
     }
 }
 
 #[derive(Component, Debug, Default)]
 pub struct ProgressAnimation {
-    pub target_fraction: f32,
-    pub current_display_fraction: f32,
-    pub start_fraction: f32, // Where the animation started from
-    pub start_time: f64,
-    pub duration: f32,
-    pub is_animating: bool,
+    /// Filled fraction currently on screen
+    pub fraction: AnimatedValue<f32>,
 }
 
 #[derive(Component)]
 pub struct ProgressBarParts {
+    pub border: Entity,
+    pub track: Entity,
     pub fill: Entity,
     pub text: Option<Entity>,
 }
@@ -184,57 +232,6 @@ pub enum FillDirection {
     BottomToTop,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
-pub enum EasingFunction {
-    Linear,
-    EaseIn,
-    EaseOut,
-    EaseInOut,
-    Bounce,
-    Elastic,
-}
-
-impl EasingFunction {
-    pub fn sample(&self, t: f32) -> f32 {
-        let t = t.clamp(0.0, 1.0);
-        match self {
-            EasingFunction::Linear => t,
-            EasingFunction::EaseIn => t * t,
-            EasingFunction::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
-            EasingFunction::EaseInOut => {
-                if t < 0.5 {
-                    2.0 * t * t
-                } else {
-                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
-                }
-            }
-            EasingFunction::Bounce => {
-                if t < 1.0 / 2.75 {
-                    7.5625 * t * t
-                } else if t < 2.0 / 2.75 {
-                    let t = t - 1.5 / 2.75;
-                    7.5625 * t * t + 0.75
-                } else if t < 2.5 / 2.75 {
-                    let t = t - 2.25 / 2.75;
-                    7.5625 * t * t + 0.9375
-                } else {
-                    let t = t - 2.625 / 2.75;
-                    7.5625 * t * t + 0.984375
-                }
-            }
-            EasingFunction::Elastic => {
-                if t == 0.0 || t == 1.0 {
-                    t
-                } else {
-                    let c4 = (2.0 * std::f32::consts::PI) / 3.0;
-                    let result = -2.0f32.powf(10.0 * t - 10.0) * ((t * 10.0 - 10.75) * c4).sin();
-                    result.clamp(0.0, 1.0) // Prevent negative overshoot
-                }
-            }
-        }
-    }
-}
-
 #[derive(Clone)]
 pub enum ProgressTextFormat {
     Percentage,
@@ -324,7 +321,6 @@ pub fn progress_bar_update_system(
     ), (With<ProgressBarRoot>, Or<(Changed<ProgressBar>, Added<ProgressBar>)>)>,
     mut q_fills: Query<&mut Node, With<ProgressBarFill>>,
     mut evw_changed: EventWriter<ProgressChangedEvent>,
-    time: Res<Time>,
 ) {
     
     for (entity, progress, visuals, parts, animation) in &mut q_progress_bars {
@@ -339,13 +335,8 @@ pub fn progress_bar_update_system(
         });
         if let Ok(mut fill_style) = q_fills.get_mut(parts.fill) {
             if let Some(mut animation) = animation {
-                // Use fixed duration from visuals
-                animation.duration = visuals.animation_duration;
-                // Record where this animation is starting from
-                animation.start_fraction = animation.current_display_fraction;
-                animation.target_fraction = target_fraction;
-                animation.start_time = time.elapsed_secs_f64();
-                animation.is_animating = true;
+                // The animation system moves the fill from wherever it is now
+                animation.fraction.set_target(target_fraction);
             } else {
                 // Immediate update (no animation component) - convert fraction to percentage for styling
                 let target_percentage = calculate_fill_percentage(progress, visuals);
@@ -363,8 +354,8 @@ pub fn progress_bar_update_system(
 }
 
 pub fn progress_bar_animation_system(
-    _commands: Commands,
     time: Res<Time>,
+    motion: Res<WidgetMotionSettings>,
     mut q_animations: Query<(
         Entity,
         &ProgressBar,
@@ -375,41 +366,29 @@ pub fn progress_bar_animation_system(
     mut q_fills: Query<&mut Node, With<ProgressBarFill>>,
     mut evw_completed: EventWriter<ProgressCompletedEvent>,
 ) {
-    let current_time = time.elapsed_secs_f64();
-    
     for (entity, progress, visuals, parts, mut animation) in &mut q_animations {
-        if animation.is_animating {
-            let elapsed = (current_time - animation.start_time) as f32;
-            let t = (elapsed / animation.duration.max(0.001)).clamp(0.0, 1.0);
-            
-            let eased_t = visuals.easing.sample(t);
-            
-            // Proper lerp from start_fraction to target_fraction
-            animation.current_display_fraction = animation.start_fraction.lerp(animation.target_fraction, eased_t);
-            
-            // Update fill - convert fraction to percentage for styling
-            if let Ok(mut fill_style) = q_fills.get_mut(parts.fill) {
-                let display_percentage = match visuals.fill_direction {
-                    FillDirection::RightToLeft | FillDirection::BottomToTop => (1.0 - animation.current_display_fraction) * 100.0,
-                    _ => animation.current_display_fraction * 100.0,
-                };
-                update_fill_style(&mut fill_style, display_percentage, visuals);
-            } else {
-                warn!("animation_system: Could not find fill entity {:?}", parts.fill);
-            }
-            
-            // Check if animation complete
-            if t >= 1.0 {
-                animation.is_animating = false;
-                animation.current_display_fraction = animation.target_fraction;
-                
-                // Fire completion event if reached max
-                if progress.current >= progress.max {
-                    evw_completed.write(ProgressCompletedEvent {
-                        progress_entity: entity,
-                    });
-                }
-            }
+        if animation.fraction.is_settled() {
+            continue;
+        }
+        let finished = animation.fraction.update(&visuals.resolved_transition(), &motion, &time);
+        let fraction = animation.fraction.value();
+        
+        // Update fill - convert fraction to percentage for styling
+        if let Ok(mut fill_style) = q_fills.get_mut(parts.fill) {
+            let display_percentage = match visuals.fill_direction {
+                FillDirection::RightToLeft | FillDirection::BottomToTop => (1.0 - fraction) * 100.0,
+                _ => fraction * 100.0,
+            };
+            update_fill_style(&mut fill_style, display_percentage, visuals);
+        } else {
+            warn!("animation_system: Could not find fill entity {:?}", parts.fill);
+        }
+        
+        // Fire completion event if the fill just reached max
+        if finished && progress.current >= progress.max {
+            evw_completed.write(ProgressCompletedEvent {
+                progress_entity: entity,
+            });
         }
     }
 }
@@ -462,6 +441,73 @@ pub fn progress_threshold_system(
     }
 }
 
+/// Copies theme colors the builder wasn't given into `ProgressBarVisuals`
+/// and onto the bar's parts, for new bars and for every bar when the theme
+/// changes.
+pub fn progress_bar_theme_system(
+    themes: ThemeLookup,
+    mut q_progress_bars: Query<(
+        Entity,
+        Ref<ProgressBarRoot>,
+        &mut ProgressBarVisuals,
+        Option<&ExplicitColors>,
+        Option<&ProgressBarParts>,
+    )>,
+    mut q_borders: Query<&mut BorderColor, With<ProgressBarBorder>>,
+    mut q_backgrounds: Query<&mut BackgroundColor, Or<(With<ProgressBarTrack>, With<ProgressBarFill>)>>,
+    mut q_texts: Query<(&mut TextColor, &mut TextFont), With<ProgressBarText>>,
+) {
+    let restyle_all = themes.changed();
+
+    for (entity, root, mut visuals, explicit, parts) in &mut q_progress_bars {
+        if !restyle_all && !root.is_added() {
+            continue;
+        }
+        let theme = themes.get(entity);
+        let explicit = explicit.copied().unwrap_or_default();
+        explicit.apply(0, &mut visuals.track_color, theme.palette.track);
+        explicit.apply(1, &mut visuals.fill_color, theme.palette.accent);
+        explicit.apply(2, &mut visuals.border_color, theme.palette.border);
+        explicit.apply(3, &mut visuals.text_color, theme.palette.text);
+
+        let Some(parts) = parts else {
+            continue;
+        };
+        if let Ok(mut border_color) = q_borders.get_mut(parts.border) {
+            border_color.0 = visuals.border_color;
+        }
+        if let Ok(mut background) = q_backgrounds.get_mut(parts.track) {
+            background.0 = visuals.track_color;
+        }
+        if let Ok(mut background) = q_backgrounds.get_mut(parts.fill) {
+            background.0 = visuals.fill_color;
+        }
+        if let Some(Ok((mut color, mut font))) = parts.text.map(|text| q_texts.get_mut(text)) {
+            color.0 = visuals.text_color;
+            *font = theme.typography.text_font(theme.typography.value_size);
+        }
+    }
+}
+
+/// Publishes each bar as a progress indicator with its value and the value
+/// text it displays.
+pub fn progress_bar_accessibility_system(
+    mut commands: Commands,
+    mut q_progress_bars: Query<
+        (Entity, &ProgressBar, &ProgressBarVisuals, Option<&mut AccessibilityNode>),
+        (With<ProgressBarRoot>, Or<(Changed<ProgressBar>, Changed<AccessibilityNode>)>)
+    >,
+) {
+    for (entity, progress, visuals, node) in &mut q_progress_bars {
+        sync_accessibility_node(&mut commands, entity, node, Role::ProgressIndicator, |node| {
+            node.set_numeric_value(progress.current as f64);
+            node.set_min_numeric_value(progress.min as f64);
+            node.set_max_numeric_value(progress.max as f64);
+            node.set_value(visuals.text_format.format(progress.current, progress.max));
+        });
+    }
+}
+
 #[cfg(debug_assertions)]
 pub fn progress_bar_contrast_check_system(
     q_progress_bars: Query<&ProgressBarVisuals, Added<ProgressBarVisuals>>,
@@ -537,6 +583,7 @@ pub struct ProgressBarBuilder<'w, 's, 'a> {
     visuals: ProgressBarVisuals,
     size: (Val, Val),
     with_animation: bool,
+    theme: Option<WidgetTheme>,
 }
 
 impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
@@ -548,6 +595,7 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
             visuals: ProgressBarVisuals::default(),
             size: (Val::Px(200.0), Val::Px(20.0)),
             with_animation: true,
+            theme: None,
         }
     }
 
@@ -557,6 +605,7 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
         self
     }
 
+    /// Colors in `visuals` that differ from the defaults win over the theme.
     pub fn with_visuals(mut self, visuals: ProgressBarVisuals) -> Self {
         self.visuals = visuals;
         self
@@ -572,6 +621,12 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
         self
     }
 
+    /// Styles this progress bar with `theme` instead of the global `WidgetTheme`.
+    pub fn with_theme(mut self, theme: WidgetTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
     pub fn spawn(mut self) -> Entity {
         self.spawn_internal(None)
     }
@@ -598,6 +653,7 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
             },
             ProgressBarRoot,
             ProgressBar::new(self.initial_value, self.max_value),
+            self.visuals.explicit_colors(),
             self.visuals.clone(),
         )).id();
 
@@ -609,7 +665,6 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
                 border: UiRect::all(Val::Px(self.visuals.border_width)),
                 ..default()
             },
-            BorderColor(self.visuals.border_color),
             ProgressBarBorder,
         )).id();
 
@@ -621,7 +676,6 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
                 padding: UiRect::all(Val::Px(self.visuals.fill_margin)),
                 ..default()
             },
-            BackgroundColor(self.visuals.track_color),
             ProgressBarTrack,
         )).id();
 
@@ -641,7 +695,6 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
 
         let fill_entity = self.commands.spawn((
             fill_node,
-            BackgroundColor(self.visuals.fill_color),
             ProgressBarFill,
         )).id();
 
@@ -649,11 +702,6 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
         let text_entity = if self.visuals.show_text {
             let text_entity = self.commands.spawn((
                 Text::new(self.visuals.text_format.format(self.initial_value, self.max_value)),
-                TextColor(self.visuals.text_color),
-                TextFont {
-                    font_size: 14.0,
-                    ..default()
-                },
                 Node {
                     position_type: PositionType::Absolute,
                     width: Val::Percent(100.0),
@@ -681,6 +729,8 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
 
         // Add parts and animation components
         self.commands.entity(root_entity).insert(ProgressBarParts {
+            border: border_entity,
+            track: track_entity,
             fill: fill_entity,
             text: text_entity,
         });
@@ -688,15 +738,14 @@ impl<'w, 's, 'a> ProgressBarBuilder<'w, 's, 'a> {
         if self.with_animation {
             let initial_fraction = (initial_percentage / 100.0).clamp(0.0, 1.0);
             self.commands.entity(root_entity).insert(ProgressAnimation {
-                target_fraction: initial_fraction,
-                current_display_fraction: initial_fraction,
-                start_time: 0.0,
-                start_fraction: 0.0,
-                duration: self.visuals.animation_duration, // Initialize with proper duration!
-                is_animating: false,
+                fraction: AnimatedValue::new(initial_fraction),
             });
         }
 
+        if let Some(theme) = self.theme.take() {
+            self.commands.entity(root_entity).insert(WidgetThemeOverride(theme));
+        }
+
         root_entity
     }
 }
@@ -709,20 +758,25 @@ pub struct ProgressBarPlugin;
 
 impl Plugin for ProgressBarPlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugin_once(WidgetInputPlugin);
+        app.add_plugin_once(WidgetThemePlugin);
+        app.add_plugin_once(WidgetMotionPlugin);
+
         app.register_type::<ProgressBar>()
             .register_type::<ProgressOrientation>()
             .register_type::<FillDirection>()
-            .register_type::<EasingFunction>()
             .add_event::<ProgressChangedEvent>()
             .add_event::<ProgressThresholdEvent>()
             .add_event::<ProgressCompletedEvent>()
             .init_resource::<ProgressThresholds>()
             .init_resource::<ProgressBarAssets>()
             .add_systems(Update, (
+                progress_bar_theme_system,
                 progress_bar_update_system,
                 progress_bar_animation_system,
                 progress_bar_text_system,
                 progress_threshold_system,
+                progress_bar_accessibility_system,
             ).chain());
 
         #[cfg(debug_assertions)]
@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+use bevy::ecs::component::Mutable;
+use crate::anim::AnimatedValue;
+use crate::binding::{Lens, WidgetBinding};
+use crate::focus::Focusable;
+use crate::theme::{WidgetTheme, WidgetThemeOverride};
+use super::components::*;
+
+const RADIO_STROKE: f32 = 2.0;
+
+/// Spawns a group of mutually exclusive options, each a circle and a label.
+///
+/// ```ignore
+/// RadioGroupBuilder::new(&mut commands)
+///     .with_option("Small")
+///     .with_option("Medium")
+///     .with_option("Large")
+///     .with_selected(1)
+///     .spawn();
+/// ```
+pub struct RadioGroupBuilder<'w, 's, 'a> {
+    commands: &'a mut Commands<'w, 's>,
+    labels: Vec<String>,
+    selected: Option<usize>,
+    config: RadioGroupConfig,
+    disabled: bool,
+    binding: Option<WidgetBinding>,
+    theme: Option<WidgetTheme>,
+    focusable: Focusable,
+}
+
+impl<'w, 's, 'a> RadioGroupBuilder<'w, 's, 'a> {
+    pub fn new(commands: &'a mut Commands<'w, 's>) -> Self {
+        Self {
+            commands,
+            labels: Vec::new(),
+            selected: None,
+            config: RadioGroupConfig::default(),
+            disabled: false,
+            binding: None,
+            theme: None,
+            focusable: Focusable::default(),
+        }
+    }
+
+    pub fn with_option(mut self, label: impl Into<String>) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    /// Without this the first option starts selected, or none with
+    /// `allow_none`.
+    pub fn with_selected(mut self, index: usize) -> Self {
+        self.selected = Some(index);
+        self
+    }
+
+    pub fn allow_none(mut self, allow_none: bool) -> Self {
+        self.config.allow_none = allow_none;
+        self
+    }
+
+    /// Colors in `config` that differ from the defaults win over the theme.
+    pub fn with_config(mut self, config: RadioGroupConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Position in Tab order; see `Focusable`. Only the selected option is
+    /// in Tab order, the arrow keys move between options.
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.focusable = Focusable::with_tab_index(tab_index);
+        self
+    }
+
+    /// Styles this radio group with `theme` instead of the global `WidgetTheme`.
+    pub fn with_theme(mut self, theme: WidgetTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Keeps the selected option and a field of resource `R` in sync both ways.
+    pub fn bind_to<R: Resource>(mut self, lens: Lens<R, Option<usize>>) -> Self {
+        self.binding = Some(WidgetBinding::resource::<RadioGroup, R>(lens));
+        self
+    }
+
+    /// Keeps the selected option and a field of component `C` on `target` in sync both ways.
+    pub fn bind_to_component<C: Component<Mutability = Mutable>>(
+        mut self,
+        target: Entity,
+        lens: Lens<C, Option<usize>>,
+    ) -> Self {
+        self.binding = Some(WidgetBinding::component::<RadioGroup, C>(target, lens));
+        self
+    }
+
+    pub fn spawn(self) -> Entity {
+        let option_count = self.labels.len();
+        let selected = match self.selected.filter(|&index| index < option_count) {
+            Some(index) => Some(index),
+            None if self.config.allow_none || option_count == 0 => None,
+            None => Some(0),
+        };
+        let tab_stop = selected.unwrap_or(0);
+
+        let group_entity = self.commands.spawn((
+            Node {
+                flex_direction: self.config.direction,
+                ..default()
+            },
+            RadioGroup { selected },
+            self.config.explicit_colors(),
+            self.config.clone(),
+        )).id();
+
+        let mut option_entities = Vec::with_capacity(option_count);
+        for (index, label) in self.labels.iter().enumerate() {
+            let progress = if selected == Some(index) { 1.0 } else { 0.0 };
+
+            let dot_entity = self.commands.spawn((
+                Node {
+                    width: Val::Percent(50.0 * progress),
+                    height: Val::Percent(50.0 * progress),
+                    ..default()
+                },
+                BorderRadius::all(Val::Percent(50.0)),
+                BackgroundColor(self.config.on_color),
+                RadioDot,
+            )).id();
+
+            let indicator_entity = self.commands.spawn((
+                Node {
+                    width: Val::Px(self.config.size),
+                    height: Val::Px(self.config.size),
+                    border: UiRect::all(Val::Px(RADIO_STROKE)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BorderRadius::all(Val::Percent(50.0)),
+                BorderColor(self.config.off_color.mix(&self.config.on_color, progress)),
+                RadioIndicator,
+            )).id();
+            self.commands.entity(indicator_entity).add_child(dot_entity);
+
+            let option_entity = self.commands.spawn((
+                Button,
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },
+                RadioOption {
+                    index,
+                    group_entity,
+                },
+                RadioOptionParts {
+                    indicator: indicator_entity,
+                    dot: dot_entity,
+                },
+                RadioAnimation {
+                    progress: AnimatedValue::new(progress),
+                },
+                RadioNeedsVisualUpdate,
+                if index == tab_stop { self.focusable } else { Focusable::with_tab_index(-1) },
+            )).id();
+
+            self.commands.entity(option_entity)
+                .add_child(indicator_entity)
+                .with_children(|parent| {
+                    parent.spawn(Text::new(label));
+                });
+
+            option_entities.push(option_entity);
+        }
+
+        self.commands.entity(group_entity)
+            .insert(RadioGroupMeta {
+                labels: self.labels,
+                option_entities: option_entities.clone(),
+                tab_index: self.focusable.tab_index,
+            })
+            .add_children(&option_entities);
+
+        if self.disabled {
+            self.commands.entity(group_entity).insert(RadioDisabled);
+        }
+
+        if let Some(binding) = self.binding {
+            self.commands.entity(group_entity).insert(binding);
+        }
+
+        if let Some(theme) = self.theme {
+            self.commands.entity(group_entity).insert(WidgetThemeOverride(theme));
+        }
+
+        group_entity
+    }
+}
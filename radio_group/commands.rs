@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use crate::binding::BindableWidget;
+use super::{components::*, events::*};
+
+/// Select a radio option from code. Changes are tagged
+/// `RadioChangeKind::Programmatic`; `select_radio_silently` skips the event,
+/// e.g. when restoring saved state. Both take an index or an `Option`, and
+/// `None` clears the selection if the group allows it.
+pub trait RadioCommandsExt {
+    fn select_radio(&mut self, index: impl Into<Option<usize>>) -> &mut Self;
+    fn select_radio_silently(&mut self, index: impl Into<Option<usize>>) -> &mut Self;
+}
+
+impl RadioCommandsExt for EntityCommands<'_> {
+    fn select_radio(&mut self, index: impl Into<Option<usize>>) -> &mut Self {
+        self.queue(select_radio(index.into(), true))
+    }
+
+    fn select_radio_silently(&mut self, index: impl Into<Option<usize>>) -> &mut Self {
+        self.queue(select_radio(index.into(), false))
+    }
+}
+
+fn select_radio(index: Option<usize>, notify: bool) -> impl FnOnce(EntityWorldMut) + Send + 'static {
+    move |mut entity: EntityWorldMut| {
+        let option_count = entity.get::<RadioGroupMeta>().map_or(0, |meta| meta.option_entities.len());
+        let allow_none = entity.get::<RadioGroupConfig>().is_some_and(|config| config.allow_none);
+        match index {
+            Some(index) if index >= option_count => {
+                warn!("Radio option {} out of range for group {:?}", index, entity.id());
+                return;
+            }
+            None if !allow_none => {
+                warn!("Radio group {:?} needs allow_none to clear its selection", entity.id());
+                return;
+            }
+            _ => {}
+        }
+        let Some(mut group) = entity.get_mut::<RadioGroup>() else {
+            return;
+        };
+        let previous_index = group.selected;
+        if previous_index == index {
+            return;
+        }
+        group.selected = index;
+
+        if notify {
+            let group_entity = entity.id();
+            entity.world_scope(|world| {
+                world.send_event(RadioChangedEvent {
+                    group_entity,
+                    previous_index,
+                    new_index: index,
+                    kind: RadioChangeKind::Programmatic,
+                });
+            });
+        }
+    }
+}
+
+impl BindableWidget for RadioGroup {
+    type Value = Option<usize>;
+
+    fn bound_value(&self) -> Option<usize> {
+        self.selected
+    }
+
+    fn apply_bound_value(entity: EntityWorldMut, value: Option<usize>) {
+        select_radio(value, false)(entity);
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use crate::anim::{AnimatedValue, Transition};
+use crate::theme::ExplicitColors;
+
+/// Which option of a radio group is selected. `None` is only reachable with
+/// `RadioGroupConfig::allow_none`.
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct RadioGroup {
+    pub selected: Option<usize>,
+}
+
+#[derive(Component, Debug, Clone, Reflect, Default)]
+pub struct RadioGroupMeta {
+    pub labels: Vec<String>,
+    pub option_entities: Vec<Entity>,
+    /// Tab position of the option that takes focus from Tab; the others can
+    /// only be reached with the arrow keys.
+    pub tab_index: i32,
+}
+
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct RadioOption {
+    pub index: usize,
+    pub group_entity: Entity,
+}
+
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct RadioOptionParts {
+    pub indicator: Entity,
+    pub dot: Entity,
+}
+
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct RadioGroupConfig {
+    /// Lets clicking the selected option clear the selection, and lets the
+    /// group start with nothing selected
+    pub allow_none: bool,
+    pub animated: bool,
+    /// How the dot grows in and shrinks out
+    pub transition: Transition,
+    pub on_color: Color,
+    pub off_color: Color,
+    /// Diameter of each option's circle
+    pub size: f32,
+    pub direction: FlexDirection,
+}
+
+impl Default for RadioGroupConfig {
+    fn default() -> Self {
+        Self {
+            allow_none: false,
+            animated: true,
+            transition: Transition::default(),
+            on_color: Color::srgb(0.2, 0.8, 0.2),
+            off_color: Color::srgb(0.5, 0.5, 0.5),
+            size: 18.0,
+            direction: FlexDirection::Column,
+        }
+    }
+}
+
+impl RadioGroupConfig {
+    /// The on and off colors that differ from the defaults, in that order.
+    pub(crate) fn explicit_colors(&self) -> ExplicitColors {
+        let default = Self::default();
+        ExplicitColors::from_defaults(&[
+            (self.on_color, default.on_color),
+            (self.off_color, default.off_color),
+        ])
+    }
+}
+
+/// The circle in front of each option's label.
+#[derive(Component)]
+pub struct RadioIndicator;
+
+/// Fills the circle of the selected option.
+#[derive(Component)]
+pub struct RadioDot;
+
+#[derive(Component)]
+pub struct RadioNeedsVisualUpdate;
+
+#[derive(Component)]
+pub struct RadioDisabled;
+
+#[derive(Component, Default)]
+pub struct RadioAnimation {
+    /// 0 when unselected, 1 when selected
+    pub progress: AnimatedValue<f32>,
+}
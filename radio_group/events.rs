@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RadioChangeKind {
+    User,
+    Programmatic,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct RadioChangedEvent {
+    pub group_entity: Entity,
+    pub previous_index: Option<usize>,
+    pub new_index: Option<usize>,
+    pub kind: RadioChangeKind,
+}
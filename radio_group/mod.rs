@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use crate::binding::{WidgetBindingPlugin, WidgetBindingSystem};
+use crate::focus::{WidgetFocusPlugin, WidgetFocusSystem};
+use crate::input::WidgetInputPlugin;
+use crate::motion::WidgetMotionPlugin;
+use crate::theme::WidgetThemePlugin;
+use crate::WidgetAppExt;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+pub mod builder;
+pub mod commands;
+
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+pub use builder::*;
+pub use commands::RadioCommandsExt;
+
+pub struct RadioPlugin;
+
+impl Plugin for RadioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin_once(WidgetInputPlugin);
+        app.add_plugin_once(WidgetFocusPlugin);
+        app.add_plugin_once(WidgetThemePlugin);
+        app.add_plugin_once(WidgetMotionPlugin);
+        app.add_plugin_once(WidgetBindingPlugin);
+
+        app
+            .add_event::<RadioChangedEvent>()
+            .configure_sets(
+                Update,
+                (
+                    RadioSystem::ProcessInput
+                        .after(bevy::input::InputSystem)
+                        .after(WidgetFocusSystem)
+                        .before(bevy::ui::UiSystem::Layout),
+                    RadioSystem::UpdateAnimation.after(RadioSystem::ProcessInput),
+                    RadioSystem::UpdateVisuals.after(RadioSystem::UpdateAnimation),
+                )
+                .chain(),
+            )
+            .configure_sets(
+                Update,
+                WidgetBindingSystem.after(RadioSystem::ProcessInput).before(RadioSystem::UpdateAnimation),
+            )
+            .add_systems(Update, (
+                radio_interaction_system.in_set(RadioSystem::ProcessInput),
+                radio_keyboard_system.in_set(RadioSystem::ProcessInput),
+                radio_animation_system.in_set(RadioSystem::UpdateAnimation),
+                radio_theme_system
+                    .in_set(RadioSystem::UpdateVisuals)
+                    .before(radio_visual_update_system),
+                radio_visual_update_system.in_set(RadioSystem::UpdateVisuals),
+                radio_focus_order_system.in_set(RadioSystem::UpdateVisuals),
+                radio_accessibility_system.in_set(RadioSystem::UpdateVisuals),
+            ));
+    }
+}
@@ -0,0 +1,261 @@
+use accesskit::{Role, Toggled};
+use bevy::a11y::AccessibilityNode;
+use bevy::ecs::entity::EntityHashSet;
+use bevy::prelude::*;
+use super::{components::*, events::*};
+use crate::accessibility::{needs_accessibility_sync, set_disabled, sync_accessibility_node};
+use crate::focus::{Focusable, WidgetFocus, WidgetFocused};
+use crate::motion::WidgetMotionSettings;
+use crate::theme::{ExplicitColors, ThemeLookup};
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum RadioSystem {
+    ProcessInput,
+    UpdateAnimation,
+    UpdateVisuals,
+}
+
+/// Selects `index` as a user change, or clears the selection when the
+/// selected option is picked again and the group allows none.
+fn pick_option(
+    group_entity: Entity,
+    group: &mut RadioGroup,
+    config: &RadioGroupConfig,
+    index: usize,
+    evw_radio_change: &mut EventWriter<RadioChangedEvent>,
+) {
+    let previous_index = group.selected;
+    let new_index = if previous_index == Some(index) && config.allow_none { None } else { Some(index) };
+    if new_index == previous_index {
+        return;
+    }
+    group.selected = new_index;
+
+    evw_radio_change.write(RadioChangedEvent {
+        group_entity,
+        previous_index,
+        new_index,
+        kind: RadioChangeKind::User,
+    });
+}
+
+pub fn radio_interaction_system(
+    q_options: Query<(&Interaction, &RadioOption), Changed<Interaction>>,
+    mut q_groups: Query<(&mut RadioGroup, &RadioGroupConfig), Without<RadioDisabled>>,
+    mut evw_radio_change: EventWriter<RadioChangedEvent>,
+) {
+    for (interaction, option) in &q_options {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok((mut group, config)) = q_groups.get_mut(option.group_entity) {
+            pick_option(option.group_entity, &mut group, config, option.index, &mut evw_radio_change);
+        }
+    }
+}
+
+/// Arrow keys move focus and the selection together to the previous or next
+/// option, wrapping at the ends. Space/Enter picks the focused option.
+pub fn radio_keyboard_system(
+    q_focused_options: Query<&RadioOption, With<WidgetFocused>>,
+    mut q_groups: Query<(&mut RadioGroup, &RadioGroupConfig, &RadioGroupMeta), Without<RadioDisabled>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<WidgetFocus>,
+    mut evw_radio_change: EventWriter<RadioChangedEvent>,
+) {
+    for focused_option in &q_focused_options {
+        let Ok((mut group, config, meta)) = q_groups.get_mut(focused_option.group_entity) else {
+            continue;
+        };
+        let option_count = meta.option_entities.len();
+        if option_count == 0 {
+            continue;
+        }
+
+        let index = focused_option.index;
+        let target = if keys.just_pressed(KeyCode::ArrowLeft) || keys.just_pressed(KeyCode::ArrowUp) {
+            if index == 0 { option_count - 1 } else { index - 1 }
+        } else if keys.just_pressed(KeyCode::ArrowRight) || keys.just_pressed(KeyCode::ArrowDown) {
+            (index + 1) % option_count
+        } else if keys.just_pressed(KeyCode::Space) || keys.just_pressed(KeyCode::Enter) {
+            pick_option(focused_option.group_entity, &mut group, config, index, &mut evw_radio_change);
+            continue;
+        } else {
+            continue;
+        };
+
+        if let Some(&option_entity) = meta.option_entities.get(target) {
+            focus.set(option_entity);
+        }
+        // Arrows always select, even with `allow_none`
+        if group.selected != Some(target) {
+            let previous_index = group.selected;
+            group.selected = Some(target);
+            evw_radio_change.write(RadioChangedEvent {
+                group_entity: focused_option.group_entity,
+                previous_index,
+                new_index: Some(target),
+                kind: RadioChangeKind::User,
+            });
+        }
+    }
+}
+
+/// Keeps only the selected option, or the first when none is, in Tab order.
+pub fn radio_focus_order_system(
+    q_groups: Query<(&RadioGroup, &RadioGroupMeta), Changed<RadioGroup>>,
+    mut q_options: Query<&mut Focusable, With<RadioOption>>,
+) {
+    for (group, meta) in &q_groups {
+        let tab_stop = group.selected.unwrap_or(0);
+        for (index, &option_entity) in meta.option_entities.iter().enumerate() {
+            if let Ok(mut focusable) = q_options.get_mut(option_entity) {
+                let tab_index = if index == tab_stop { meta.tab_index } else { -1 };
+                focusable.set_if_neq(Focusable::with_tab_index(tab_index));
+            }
+        }
+    }
+}
+
+pub fn radio_animation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    motion: Res<WidgetMotionSettings>,
+    mut q_options: Query<(Entity, &RadioOption, &mut RadioAnimation)>,
+    q_groups: Query<(&RadioGroup, &RadioGroupConfig)>,
+) {
+    for (entity, option, mut animation) in &mut q_options {
+        let Ok((group, config)) = q_groups.get(option.group_entity) else {
+            continue;
+        };
+        let target = if group.selected == Some(option.index) { 1.0 } else { 0.0 };
+        if animation.progress.is_settled() && animation.progress.target() == target {
+            continue;
+        }
+
+        animation.progress.set_target(target);
+        if config.animated {
+            animation.progress.update(&config.transition, &motion, &time);
+        } else {
+            animation.progress.jump_to(target);
+        }
+
+        // The dot follows the animation every frame until it settles
+        commands.entity(entity).insert(RadioNeedsVisualUpdate);
+    }
+}
+
+pub fn radio_visual_update_system(
+    mut commands: Commands,
+    q_options: Query<(Entity, &RadioOption, &RadioOptionParts, &RadioAnimation), With<RadioNeedsVisualUpdate>>,
+    q_groups: Query<(&RadioGroupConfig, Has<RadioDisabled>)>,
+    mut q_indicators: Query<&mut BorderColor, With<RadioIndicator>>,
+    mut q_dots: Query<(&mut Node, &mut BackgroundColor), With<RadioDot>>,
+) {
+    for (entity, option, parts, animation) in &q_options {
+        commands.entity(entity).remove::<RadioNeedsVisualUpdate>();
+        let Ok((config, disabled)) = q_groups.get(option.group_entity) else {
+            continue;
+        };
+        let progress = animation.progress.value();
+        let alpha = if disabled { 0.5 } else { 1.0 };
+
+        if let Ok(mut border) = q_indicators.get_mut(parts.indicator) {
+            border.0 = config.off_color.mix(&config.on_color, progress).with_alpha(alpha);
+        }
+        if let Ok((mut node, mut color)) = q_dots.get_mut(parts.dot) {
+            node.width = Val::Percent(50.0 * progress);
+            node.height = Val::Percent(50.0 * progress);
+            color.0 = config.on_color.with_alpha(alpha);
+        }
+    }
+}
+
+/// Copies theme colors the builder wasn't given into each radio group's
+/// config and styles its labels, on spawn and whenever the theme changes.
+/// Groups that become enabled or disabled are redrawn too.
+pub fn radio_theme_system(
+    mut commands: Commands,
+    themes: ThemeLookup,
+    mut q_groups: Query<(
+        Entity,
+        Ref<RadioGroup>,
+        &mut RadioGroupConfig,
+        Option<&ExplicitColors>,
+        &RadioGroupMeta,
+        &mut Node,
+    )>,
+    mut removed_disabled: RemovedComponents<RadioDisabled>,
+    q_added_disabled: Query<Entity, Added<RadioDisabled>>,
+    q_options: Query<&Children, With<RadioOption>>,
+    mut q_labels: Query<(&mut TextColor, &mut TextFont), With<Text>>,
+) {
+    let restyle_all = themes.changed();
+    let toggled_disabled: Vec<Entity> = removed_disabled.read().chain(&q_added_disabled).collect();
+
+    for (entity, group, mut config, explicit, meta, mut node) in &mut q_groups {
+        let restyle = restyle_all || group.is_added();
+        if !restyle && !toggled_disabled.contains(&entity) {
+            continue;
+        }
+
+        if restyle {
+            let theme = themes.get(entity);
+            let explicit = explicit.copied().unwrap_or_default();
+            explicit.apply(0, &mut config.on_color, theme.palette.accent);
+            explicit.apply(1, &mut config.off_color, theme.palette.border);
+            node.row_gap = Val::Px(theme.spacing.gap);
+            node.column_gap = Val::Px(theme.spacing.padding);
+
+            for children in q_options.iter_many(&meta.option_entities) {
+                let mut labels = q_labels.iter_many_mut(children.iter());
+                while let Some((mut color, mut font)) = labels.fetch_next() {
+                    color.0 = theme.palette.control_text;
+                    *font = theme.typography.text_font(theme.typography.body_size);
+                }
+            }
+        }
+
+        for &option_entity in &meta.option_entities {
+            commands.entity(option_entity).insert(RadioNeedsVisualUpdate);
+        }
+    }
+}
+
+/// Publishes each radio group as a radiogroup and its options as radio
+/// buttons with their checked state.
+pub fn radio_accessibility_system(
+    mut commands: Commands,
+    mut q_groups: Query<
+        (Entity, Ref<RadioGroup>, Has<RadioDisabled>, Option<&mut AccessibilityNode>),
+        Without<RadioOption>
+    >,
+    mut q_options: Query<(Entity, &RadioOption, Option<&mut AccessibilityNode>), Without<RadioGroup>>,
+    q_added_disabled: Query<(), Added<RadioDisabled>>,
+    mut removed_disabled: RemovedComponents<RadioDisabled>,
+) {
+    let enabled: EntityHashSet = removed_disabled.read().collect();
+    let disabled_changed = |group: Entity| q_added_disabled.contains(group) || enabled.contains(&group);
+
+    for (entity, _, disabled, node) in &mut q_groups {
+        if needs_accessibility_sync(disabled_changed(entity), &node) {
+            sync_accessibility_node(&mut commands, entity, node, Role::RadioGroup, |node| {
+                set_disabled(node, disabled);
+            });
+        }
+    }
+
+    for (entity, option, node) in &mut q_options {
+        let Ok((_, group, disabled, _)) = q_groups.get(option.group_entity) else {
+            continue;
+        };
+        if !needs_accessibility_sync(group.is_changed() || disabled_changed(option.group_entity), &node) {
+            continue;
+        }
+        let checked = group.selected == Some(option.index);
+        sync_accessibility_node(&mut commands, entity, node, Role::RadioButton, |node| {
+            node.set_toggled(if checked { Toggled::True } else { Toggled::False });
+            set_disabled(node, disabled);
+        });
+    }
+}
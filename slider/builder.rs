@@ -1,5 +1,10 @@
 use bevy::prelude::*;
 use bevy::ui::*;
+use bevy::ecs::component::Mutable;
+use crate::binding::{Lens, WidgetBinding};
+use crate::focus::{FocusRingTarget, Focusable};
+use crate::label::{LabelPosition, LabelText};
+use crate::theme::{WidgetTheme, WidgetThemeOverride};
 use super::components::*;
 use std::sync::Arc;
 
@@ -10,6 +15,14 @@ pub struct SliderBuilder<'w, 's, 'a> {
     handle_node: Node,
     fill_node: Node,
     text_style: TextFont,
+    ticks: Option<SliderTickPlacement>,
+    tick_snap_distance: Option<f32>,
+    wheel: Option<SliderWheel>,
+    value_input: bool,
+    binding: Option<WidgetBinding>,
+    theme: Option<WidgetTheme>,
+    focusable: Focusable,
+    label_text: LabelText,
 }
 
 impl<'w, 's, 'a> SliderBuilder<'w, 's, 'a> {
@@ -40,6 +53,14 @@ impl<'w, 's, 'a> SliderBuilder<'w, 's, 'a> {
                 font_size: 16.0,
                 ..default()
             },
+            ticks: None,
+            tick_snap_distance: None,
+            wheel: None,
+            value_input: false,
+            binding: None,
+            theme: None,
+            focusable: Focusable::default(),
+            label_text: LabelText::default(),
         }
     }
 
@@ -60,11 +81,62 @@ impl<'w, 's, 'a> SliderBuilder<'w, 's, 'a> {
         self
     }
 
+    pub fn with_page_step(mut self, page_step: f32) -> Self {
+        self.bundle.slider.page_step = Some(page_step);
+        self
+    }
+
+    pub fn with_scale(mut self, scale: SliderScale) -> Self {
+        self.bundle.slider.scale = scale;
+        self
+    }
+
     pub fn with_orientation(mut self, orientation: SliderOrientation) -> Self {
         self.bundle.slider.orientation = orientation;
         self
     }
 
+    pub fn with_emit_mode(mut self, emit_mode: SliderEmitMode) -> Self {
+        self.bundle.emit_mode = emit_mode;
+        self
+    }
+
+    /// Adds a click-to-edit field showing the value, parsed back through the `ValueFormat`.
+    pub fn with_value_input(mut self, enabled: bool) -> Self {
+        self.value_input = enabled;
+        self
+    }
+
+    /// Parser for typed values, mainly for pairing with a custom formatter.
+    pub fn with_value_parser<F>(mut self, parser: F) -> Self
+    where
+        F: Fn(&str) -> Option<f32> + Send + Sync + 'static,
+    {
+        self.bundle.options.parser = Some(ValueParser(Arc::new(parser)));
+        self
+    }
+
+    pub fn with_track_click(mut self, track_click: SliderTrackClick) -> Self {
+        self.bundle.track_click = track_click;
+        self
+    }
+
+    pub fn with_mouse_wheel(mut self, wheel: SliderWheel) -> Self {
+        self.wheel = Some(wheel);
+        self
+    }
+
+    pub fn with_ticks(mut self, placement: SliderTickPlacement) -> Self {
+        self.ticks = Some(placement);
+        self
+    }
+
+    /// While dragging, snap to the nearest tick within `distance` logical pixels.
+    pub fn with_tick_snap(mut self, distance: f32) -> Self {
+        self.tick_snap_distance = Some(distance);
+        self
+    }
+
     pub fn with_value_formatter<F>(mut self, formatter: F) -> Self
     where
         F: Fn(f32) -> String + Send + Sync + 'static,
@@ -73,91 +145,386 @@ impl<'w, 's, 'a> SliderBuilder<'w, 's, 'a> {
         self
     }
 
+    /// Position in Tab order; see `Focusable`.
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.focusable = Focusable::with_tab_index(tab_index);
+        self
+    }
+
+    /// Styles this slider with `theme` instead of the global `WidgetTheme`.
+    pub fn with_theme(mut self, theme: WidgetTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Shows `text` beside the slider. Clicking it focuses the slider.
+    /// Place the labelled slider with `spawn_with_parent`.
+    pub fn with_label(mut self, text: impl Into<String>, position: LabelPosition) -> Self {
+        self.label_text.set_label(text, position);
+        self
+    }
+
+    /// Shows `text` in smaller type under the label.
+    pub fn with_description(mut self, text: impl Into<String>) -> Self {
+        self.label_text.set_description(text);
+        self
+    }
+
+    /// Keeps the slider and a field of resource `R` in sync both ways.
+    pub fn bind_to<R: Resource>(mut self, lens: Lens<R, f32>) -> Self {
+        self.binding = Some(WidgetBinding::resource::<Slider, R>(lens));
+        self
+    }
+
+    /// Keeps the slider and a field of component `C` on `target` in sync both ways.
+    pub fn bind_to_component<C: Component<Mutability = Mutable>>(
+        mut self,
+        target: Entity,
+        lens: Lens<C, f32>,
+    ) -> Self {
+        self.binding = Some(WidgetBinding::component::<Slider, C>(target, lens));
+        self
+    }
+
+    /// Returns the slider. With a label or description the slider sits
+    /// inside its label container; place it with `spawn_with_parent`.
     pub fn spawn(self) -> Entity {
-        // let slider_entity = self.commands.spawn(self.bundle).id();
+        self.spawn_internal().1
+    }
+
+    /// Adds the slider, or its label container if it has a label, to
+    /// `parent_entity`.
+    pub fn spawn_with_parent(self, parent_entity: Entity) -> Entity {
+        let (commands, slider_entity, root_entity) = self.spawn_internal();
+        commands.entity(parent_entity).add_children(&[root_entity]);
+        slider_entity
+    }
 
+    /// Returns the slider and the entity to place in the layout.
+    fn spawn_internal(self) -> (&'a mut Commands<'w, 's>, Entity, Entity) {
         let track_entity = self.commands.spawn((
             self.track_node,
-            BackgroundColor(Color::srgb(0.5, 0.5, 0.5)),
             SliderTrack,
+            Interaction::None,
         )).id();
 
         let fill_entity = self.commands.spawn((
             self.fill_node,
-            BackgroundColor(Color::srgb(0.0, 0.0, 1.0)),
             SliderFill,
         )).id();
 
         let handle_entity = self.commands.spawn((
             self.handle_node,
-            BackgroundColor(Color::WHITE),
             SliderHandle,
             Interaction::None,
             FocusPolicy::Pass,
         )).id();
-        
-	    let slider_entity = self.commands.spawn(SliderBundle {
-	        slider: Slider {
-	            handle_entity, // <- Store the handle entity
-	            ..self.bundle.slider
-	        },
-	        ..self.bundle
-	    }).id();        
 
-        let text_entity = self.commands.spawn((
-            Text::new(""),
-            self.text_style,
-            TextColor(Color::WHITE),
-            SliderValueText,
-        )).id();
+        let ticks = self.ticks
+            .map(|placement| placement.resolve(&self.bundle.slider, &self.bundle.options.format))
+            .unwrap_or_default();
+        let tick_entities: Vec<Entity> = ticks
+            .iter()
+            .map(|tick| spawn_tick_mark(self.commands, tick, &self.bundle.slider, &self.text_style))
+            .collect();
+
+        let slider_entity = self.commands.spawn(SliderBundle {
+            slider: Slider {
+                handle_entity, // <- Store the handle entity
+                ..self.bundle.slider
+            },
+            ..self.bundle
+        }).id();
+
+        self.commands.entity(slider_entity).insert((self.focusable, FocusRingTarget(handle_entity)));
+
+        if let Some(wheel) = self.wheel {
+            self.commands.entity(slider_entity).insert(wheel);
+        }
+
+        if let Some(binding) = self.binding {
+            self.commands.entity(slider_entity).insert(binding);
+        }
+
+        if let Some(theme) = self.theme {
+            self.commands.entity(slider_entity).insert(WidgetThemeOverride(theme));
+        }
 
+        if !ticks.is_empty() {
+            self.commands.entity(slider_entity).insert(SliderTicks {
+                ticks,
+                snap_distance: self.tick_snap_distance,
+            });
+        }
+
+        let text_entity = if self.value_input {
+            self.commands.spawn((
+                Button,
+                Node {
+                    min_width: Val::Px(56.0),
+                    margin: UiRect::left(Val::Px(8.0)),
+                    padding: UiRect::axes(Val::Px(4.0), Val::Px(2.0)),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BorderColor::default(),
+                Text::new(""),
+                self.text_style,
+                SliderValueInput { slider: slider_entity },
+            )).id()
+        } else {
+            self.commands.spawn((
+                Text::new(""),
+                self.text_style,
+                SliderValueText,
+            )).id()
+        };
+
+        // Ticks go first so the fill and handle draw over them
+        self.commands.entity(track_entity).add_children(&tick_entities);
         self.commands.entity(track_entity).add_children(&[fill_entity, handle_entity]);
         self.commands.entity(slider_entity).add_children(&[track_entity, text_entity]);
 
-        slider_entity
+        let root_entity = self.label_text.spawn(self.commands, slider_entity).unwrap_or(slider_entity);
+
+        (self.commands, slider_entity, root_entity)
     }
-    
+}
+
+fn spawn_tick_mark(
+    commands: &mut Commands,
+    tick: &SliderTick,
+    slider: &Slider,
+    text_style: &TextFont,
+) -> Entity {
+    let percent = slider.scale.position_from_value(tick.value, slider.min, slider.max) * 100.0;
+    let node = match slider.orientation {
+        SliderOrientation::Horizontal => Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(percent),
+            width: Val::Px(2.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        SliderOrientation::Vertical => Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Percent(percent),
+            width: Val::Percent(100.0),
+            height: Val::Px(2.0),
+            ..default()
+        },
+    };
+
+    let tick_entity = commands.spawn((
+        node,
+        FocusPolicy::Pass,
+        SliderTickMark { value: tick.value },
+    )).id();
+
+    if let Some(label) = &tick.label {
+        let label_node = match slider.orientation {
+            SliderOrientation::Horizontal => Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(100.0),
+                ..default()
+            },
+            SliderOrientation::Vertical => Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(100.0),
+                ..default()
+            },
+        };
+        let label_entity = commands.spawn((
+            label_node,
+            Text::new(label.clone()),
+            TextFont {
+                font_size: text_style.font_size * 0.75,
+                ..text_style.clone()
+            },
+            SliderTickLabel,
+        )).id();
+        commands.entity(tick_entity).add_child(label_entity);
+    }
+
+    tick_entity
+}
+
+pub struct RangeSliderBuilder<'w, 's, 'a> {
+    commands: &'a mut Commands<'w, 's>,
+    bundle: RangeSliderBundle,
+    range: Option<(f32, f32)>,
+    values: Option<(f32, f32)>,
+    handle_node: Node,
+    text_style: TextFont,
+    theme: Option<WidgetTheme>,
+}
+
+impl<'w, 's, 'a> RangeSliderBuilder<'w, 's, 'a> {
+    pub fn new(commands: &'a mut Commands<'w, 's>) -> Self {
+        Self {
+            commands,
+            bundle: RangeSliderBundle::default(),
+            range: None,
+            values: None,
+            handle_node: Node {
+                width: Val::Px(16.0),
+                height: Val::Px(16.0),
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            text_style: TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            theme: None,
+        }
+    }
+
+    /// Without `with_values`, the handles start at `min` and `max`.
+    pub fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Clamped to the range and widened to `min_gap` when spawned, so the
+    /// order of the builder calls doesn't matter.
+    pub fn with_values(mut self, low: f32, high: f32) -> Self {
+        self.values = Some((low, high));
+        self
+    }
+
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.bundle.range.step = Some(step);
+        self
+    }
+
+    pub fn with_min_gap(mut self, min_gap: f32) -> Self {
+        self.bundle.range.min_gap = min_gap.max(0.0);
+        self
+    }
+
+    pub fn with_orientation(mut self, orientation: SliderOrientation) -> Self {
+        self.bundle.range.orientation = orientation;
+        self
+    }
+
+    pub fn with_value_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(f32) -> String + Send + Sync + 'static,
+    {
+        self.bundle.options.format = ValueFormat::Custom(Arc::new(formatter));
+        self
+    }
+
+    /// Styles this range slider with `theme` instead of the global `WidgetTheme`.
+    pub fn with_theme(mut self, theme: WidgetTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    pub fn spawn(self) -> Entity {
+        self.spawn_internal().1
+    }
+
     pub fn spawn_with_parent(self, parent_entity: Entity) -> Entity {
-        // Duplicate the spawn logic to avoid the move issue
+        let (commands, range_entity) = self.spawn_internal();
+        commands.entity(parent_entity).add_children(&[range_entity]);
+        range_entity
+    }
+
+    fn spawn_internal(mut self) -> (&'a mut Commands<'w, 's>, Entity) {
+        self.resolve_values();
+        let orientation = self.bundle.range.orientation;
+
         let track_entity = self.commands.spawn((
-            self.track_node,
-            BackgroundColor(Color::srgb(0.5, 0.5, 0.5)),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
             SliderTrack,
         )).id();
 
+        let fill_node = match orientation {
+            SliderOrientation::Horizontal => Node {
+                position_type: PositionType::Absolute,
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            SliderOrientation::Vertical => Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                ..default()
+            },
+        };
         let fill_entity = self.commands.spawn((
-            self.fill_node,
-            BackgroundColor(Color::srgb(0.0, 0.0, 1.0)),
+            fill_node,
             SliderFill,
         )).id();
 
-        let handle_entity = self.commands.spawn((
-            self.handle_node,
-            BackgroundColor(Color::WHITE),
-            SliderHandle,
-            Interaction::None,
-            FocusPolicy::Pass,
-        )).id();
-        
-        let slider_entity = self.commands.spawn(SliderBundle {
-            slider: Slider {
-                handle_entity,
-                ..self.bundle.slider
+        // Each thumb is its own tab stop
+        let mut spawn_handle = |handle: RangeHandle| {
+            self.commands.spawn((
+                self.handle_node.clone(),
+                RangeSliderHandle(handle),
+                Focusable::default(),
+                SliderKeyRepeat::default(),
+                Interaction::None,
+                FocusPolicy::Pass,
+            )).id()
+        };
+        let low_handle = spawn_handle(RangeHandle::Low);
+        let high_handle = spawn_handle(RangeHandle::High);
+
+        let range_entity = self.commands.spawn((
+            RangeSliderBundle {
+                range: RangeSlider {
+                    low_handle,
+                    high_handle,
+                    ..self.bundle.range
+                },
+                ..self.bundle
             },
-            ..self.bundle
-        }).id();        
+            SliderNeedsVisualUpdate,
+        )).id();
 
         let text_entity = self.commands.spawn((
             Text::new(""),
             self.text_style,
-            TextColor(Color::WHITE),
             SliderValueText,
         )).id();
 
-        self.commands.entity(track_entity).add_children(&[fill_entity, handle_entity]);
-        self.commands.entity(slider_entity).add_children(&[track_entity, text_entity]);
-        self.commands.entity(parent_entity).add_children(&[slider_entity]);
+        self.commands.entity(track_entity).add_children(&[fill_entity, low_handle, high_handle]);
+        self.commands.entity(range_entity).add_children(&[track_entity, text_entity]);
 
-        slider_entity
-    }    
-}
\ No newline at end of file
+        if let Some(theme) = self.theme {
+            self.commands.entity(range_entity).insert(WidgetThemeOverride(theme));
+        }
+
+        (self.commands, range_entity)
+    }
+
+    fn resolve_values(&mut self) {
+        let range = &mut self.bundle.range;
+        if let Some((min, max)) = self.range {
+            range.min = min.min(max);
+            range.max = max.max(min);
+        }
+        let (low, high) = match (self.values, self.range) {
+            (Some(values), _) => values,
+            (None, Some(_)) => (range.min, range.max),
+            (None, None) => (range.low, range.high),
+        };
+        let (low, high) = (
+            low.min(high).clamp(range.min, range.max),
+            low.max(high).clamp(range.min, range.max),
+        );
+
+        // Widen upwards first, then downwards if the top of the range is in the way
+        let gap = range.min_gap.min(range.max - range.min);
+        range.high = high.max(low + gap).min(range.max);
+        range.low = low.min(range.high - gap);
+    }
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use crate::binding::BindableWidget;
+use super::{components::*, events::*, systems::apply_step};
+
+/// Set a slider (or knob) value from code. The value is stepped and clamped
+/// like user input, and the change is tagged `SliderChangeKind::Programmatic`.
+/// `set_slider_value_silently` skips the events, e.g. when restoring saved state.
+pub trait SliderCommandsExt {
+    fn set_slider_value(&mut self, value: f32) -> &mut Self;
+    fn set_slider_value_silently(&mut self, value: f32) -> &mut Self;
+}
+
+impl SliderCommandsExt for EntityCommands<'_> {
+    fn set_slider_value(&mut self, value: f32) -> &mut Self {
+        self.queue(set_slider_value(value, true))
+    }
+
+    fn set_slider_value_silently(&mut self, value: f32) -> &mut Self {
+        self.queue(set_slider_value(value, false))
+    }
+}
+
+fn set_slider_value(value: f32, notify: bool) -> impl FnOnce(EntityWorldMut) + Send + 'static {
+    move |mut entity: EntityWorldMut| {
+        let release_mode = entity.get::<SliderEmitMode>() == Some(&SliderEmitMode::OnRelease);
+        let Some(mut slider) = entity.get_mut::<Slider>() else {
+            return;
+        };
+        let new_value = apply_step(value, slider.min, slider.step).clamp(slider.min, slider.max);
+        let previous_value = slider.value;
+        if (new_value - previous_value).abs() <= f32::EPSILON {
+            return;
+        }
+        slider.value = new_value;
+        let (handle_entity, orientation) = (slider.handle_entity, slider.orientation);
+        entity.insert(SliderNeedsVisualUpdate);
+
+        if notify {
+            let slider_entity = entity.id();
+            entity.world_scope(|world| {
+                world.send_event(SliderValueChangedEvent {
+                    entity: slider_entity,
+                    handle_entity,
+                    previous_value,
+                    new_value,
+                    orientation,
+                    preview: false,
+                    kind: SliderChangeKind::Programmatic,
+                });
+                // Nothing to wait for, so release-mode listeners hear it straight away
+                if release_mode {
+                    world.send_event(SliderCommittedEvent {
+                        entity: slider_entity,
+                        handle_entity,
+                        start_value: previous_value,
+                        final_value: new_value,
+                        orientation,
+                    });
+                }
+            });
+        }
+    }
+}
+
+impl BindableWidget for Slider {
+    type Value = f32;
+
+    fn bound_value(&self) -> f32 {
+        self.value
+    }
+
+    fn apply_bound_value(entity: EntityWorldMut, value: f32) {
+        set_slider_value(value, false)(entity);
+    }
+}
@@ -9,24 +9,156 @@ pub struct Slider {
     pub step: Option<f32>,
     pub orientation: SliderOrientation,
     pub value: f32,
+    pub page_step: Option<f32>,
+    pub scale: SliderScale,
     pub handle_entity: Entity, // <- NEW: Track the handle entity
 }
 
+/// How positions along the track (0.0 at `min`, 1.0 at `max`) map to values.
+#[derive(Clone, Default)]
+pub enum SliderScale {
+    #[default]
+    Linear,
+    /// Equal track distances multiply the value by the same factor.
+    /// Needs `min > 0.0`; falls back to linear otherwise.
+    Logarithmic,
+    /// `value = min + (max - min) * position^power`
+    Exponential(f32),
+    Custom {
+        to_value: Arc<dyn Fn(f32) -> f32 + Send + Sync>,
+        to_position: Arc<dyn Fn(f32) -> f32 + Send + Sync>,
+    },
+}
+
+impl std::fmt::Debug for SliderScale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SliderScale::Linear => write!(f, "Linear"),
+            SliderScale::Logarithmic => write!(f, "Logarithmic"),
+            SliderScale::Exponential(power) => write!(f, "Exponential({})", power),
+            SliderScale::Custom { .. } => write!(f, "Custom(fn)"),
+        }
+    }
+}
+
+impl SliderScale {
+    pub fn value_from_position(&self, position: f32, min: f32, max: f32) -> f32 {
+        let position = position.clamp(0.0, 1.0);
+        match self {
+            SliderScale::Logarithmic if min > 0.0 && max > min => min * (max / min).powf(position),
+            SliderScale::Exponential(power) if *power > 0.0 => min + (max - min) * position.powf(*power),
+            SliderScale::Custom { to_value, .. } => to_value(position).clamp(min.min(max), max.max(min)),
+            _ => min + (max - min) * position,
+        }
+    }
+
+    pub fn position_from_value(&self, value: f32, min: f32, max: f32) -> f32 {
+        let span = max - min;
+        if span.abs() <= f32::EPSILON {
+            return 0.0;
+        }
+        let position = match self {
+            SliderScale::Logarithmic if min > 0.0 && max > min => {
+                (value.max(min) / min).ln() / (max / min).ln()
+            }
+            SliderScale::Exponential(power) if *power > 0.0 => {
+                ((value - min) / span).clamp(0.0, 1.0).powf(power.recip())
+            }
+            SliderScale::Custom { to_position, .. } => to_position(value),
+            _ => (value - min) / span,
+        };
+        position.clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct RangeSlider {
+    pub min: f32,
+    pub max: f32,
+    pub step: Option<f32>,
+    pub orientation: SliderOrientation,
+    pub low: f32,
+    pub high: f32,
+    pub min_gap: f32,
+    pub low_handle: Entity,
+    pub high_handle: Entity,
+}
+
+impl RangeSlider {
+    /// Clamps a candidate position for one handle so it stays inside the
+    /// range and at least `min_gap` away from the other handle.
+    pub fn constrain(&self, handle: RangeHandle, value: f32) -> f32 {
+        match handle {
+            RangeHandle::Low => value.clamp(self.min, (self.high - self.min_gap).max(self.min)),
+            RangeHandle::High => value.clamp((self.low + self.min_gap).min(self.max), self.max),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeHandle {
+    Low,
+    High,
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RangeSliderHandle(pub RangeHandle);
+
+#[derive(Component, Default)]
+pub struct RangeSliderDrag {
+    pub active: Option<RangeHandle>,
+}
+
 #[derive(Component, Debug, Clone)]
 pub struct SliderOptions {
     pub format: ValueFormat,
     pub show_value: bool,
+    pub parser: Option<ValueParser>,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SliderEmitMode {
+    #[default]
     Continuous,
     OnRelease,
 }
 
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SliderTrackClick {
+    /// Clicks on the track are ignored; only the handle can be dragged.
+    #[default]
+    Ignore,
+    /// Jump the handle to the cursor and keep dragging from there.
+    JumpToCursor,
+    /// Move a page step toward the cursor, repeating while held.
+    PageTowardCursor,
+}
+
+#[derive(Component, Default)]
+pub struct SliderTrackRepeat {
+    pub timer: Timer,
+}
+
+/// Enables mouse-wheel stepping while the slider is hovered.
+#[derive(Component, Debug, Clone)]
+pub struct SliderWheel {
+    /// Holding any of these scrolls by `fine_factor` of a step, off the step grid.
+    pub fine_modifiers: Vec<KeyCode>,
+    pub fine_factor: f32,
+}
+
+impl Default for SliderWheel {
+    fn default() -> Self {
+        Self {
+            fine_modifiers: vec![KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            fine_factor: 0.1,
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct SliderPendingChange {
-    pub value: f32,
+    pub start_value: f32,
 }
 
 #[derive(Clone)]
@@ -60,8 +192,103 @@ impl ValueFormat {
             ValueFormat::Custom(f) => f(value),
         }
     }
+
+    /// Reads typed text back into a value. A custom `parser` takes priority;
+    /// otherwise `Percent` accepts an optional trailing `%` and everything
+    /// else expects a plain number.
+    pub fn parse(&self, input: &str, parser: Option<&ValueParser>) -> Option<f32> {
+        let input = input.trim();
+        if let Some(parser) = parser {
+            return (parser.0)(input).filter(|value| value.is_finite());
+        }
+        let value = match self {
+            ValueFormat::Percent(_) => {
+                input.strip_suffix('%').unwrap_or(input).trim_end().parse::<f32>().ok()? / 100.0
+            }
+            ValueFormat::Precision(_) | ValueFormat::Custom(_) => input.parse::<f32>().ok()?,
+        };
+        value.is_finite().then_some(value)
+    }
+}
+
+#[derive(Clone)]
+pub struct ValueParser(pub Arc<dyn Fn(&str) -> Option<f32> + Send + Sync>);
+
+impl std::fmt::Debug for ValueParser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ValueParser(fn)")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SliderTick {
+    pub value: f32,
+    pub label: Option<String>,
+}
+
+impl SliderTick {
+    pub fn new(value: f32) -> Self {
+        Self { value, label: None }
+    }
+
+    pub fn labeled(value: f32, label: impl Into<String>) -> Self {
+        Self { value, label: Some(label.into()) }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SliderTickPlacement {
+    /// One tick per `Slider::step`, or ten intervals when there is no step.
+    /// Labeled ticks use the slider's `ValueFormat`.
+    Step { labeled: bool },
+    Values(Vec<SliderTick>),
+}
+
+impl SliderTickPlacement {
+    pub fn resolve(&self, slider: &Slider, format: &ValueFormat) -> Vec<SliderTick> {
+        match self {
+            SliderTickPlacement::Values(ticks) => ticks
+                .iter()
+                .filter(|tick| tick.value >= slider.min && tick.value <= slider.max)
+                .cloned()
+                .collect(),
+            SliderTickPlacement::Step { labeled } => {
+                let span = slider.max - slider.min;
+                let step = slider.step.filter(|step| *step > 0.0).unwrap_or(span / 10.0);
+                if step <= 0.0 {
+                    return Vec::new();
+                }
+                // Cap runaway tick counts from tiny steps
+                let count = ((span / step).floor() as usize).min(1000);
+                (0..=count)
+                    .map(|i| {
+                        let value = slider.min + step * i as f32;
+                        SliderTick {
+                            value,
+                            label: labeled.then(|| format.format(value)),
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Default)]
+pub struct SliderTicks {
+    pub ticks: Vec<SliderTick>,
+    /// Snap a drag to the nearest tick within this many logical pixels.
+    pub snap_distance: Option<f32>,
+}
+
+#[derive(Component)]
+pub struct SliderTickMark {
+    pub value: f32,
 }
 
+#[derive(Component)]
+pub struct SliderTickLabel;
+
 #[derive(Component)]
 pub struct SliderHandle;
 
@@ -77,6 +304,27 @@ pub struct SliderValueText;
 #[derive(Component)]
 pub struct SliderNeedsVisualUpdate;
 
+#[derive(Component)]
+pub struct SliderValueInput {
+    pub slider: Entity,
+}
+
+#[derive(Component, Default)]
+pub struct SliderValueEditing {
+    pub buffer: String,
+    /// The first keystroke replaces the whole value, like a select-all on focus.
+    pub replace_on_type: bool,
+}
+
+#[derive(Component)]
+pub struct SliderValueInputInvalid;
+
+#[derive(Component, Default)]
+pub struct SliderKeyRepeat {
+    pub timer: Timer,
+    pub held_key: Option<KeyCode>,
+}
+
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SliderOrientation {
     Horizontal,
@@ -87,9 +335,13 @@ pub enum SliderOrientation {
 pub struct SliderBundle {
     pub slider: Slider,
     pub options: SliderOptions,
+    pub emit_mode: SliderEmitMode,
+    pub track_click: SliderTrackClick,
+    pub track_repeat: SliderTrackRepeat,
     pub node: Node,
     pub background_color: BackgroundColor,
     pub focus_policy: FocusPolicy,
+    pub key_repeat: SliderKeyRepeat,
 }
 
 impl Default for SliderBundle {
@@ -101,12 +353,62 @@ impl Default for SliderBundle {
                 step: None,
                 orientation: SliderOrientation::Horizontal,
                 value: 0.5,
+                page_step: None,
+                scale: SliderScale::Linear,
                 handle_entity: Entity::PLACEHOLDER,
             },
             options: SliderOptions {
                 format: ValueFormat::default(),
                 show_value: true,
+                parser: None,
+            },
+            emit_mode: SliderEmitMode::default(),
+            track_click: SliderTrackClick::default(),
+            track_repeat: SliderTrackRepeat::default(),
+            node: Node {
+                width: Val::Px(200.0),
+                height: Val::Px(24.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: BackgroundColor(Color::NONE),
+            focus_policy: FocusPolicy::Block,
+            key_repeat: SliderKeyRepeat::default(),
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct RangeSliderBundle {
+    pub range: RangeSlider,
+    pub options: SliderOptions,
+    pub drag: RangeSliderDrag,
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub focus_policy: FocusPolicy,
+}
+
+impl Default for RangeSliderBundle {
+    fn default() -> Self {
+        Self {
+            range: RangeSlider {
+                min: 0.0,
+                max: 1.0,
+                step: None,
+                orientation: SliderOrientation::Horizontal,
+                low: 0.25,
+                high: 0.75,
+                min_gap: 0.0,
+                low_handle: Entity::PLACEHOLDER,
+                high_handle: Entity::PLACEHOLDER,
+            },
+            options: SliderOptions {
+                format: ValueFormat::default(),
+                show_value: true,
+                parser: None,
             },
+            drag: RangeSliderDrag::default(),
             node: Node {
                 width: Val::Px(200.0),
                 height: Val::Px(24.0),
@@ -1,6 +1,12 @@
 use bevy::prelude::*;
 use super::components::SliderOrientation;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SliderChangeKind {
+    User,
+    Programmatic,
+}
+
 #[derive(Event, Debug, Clone)]
 pub struct SliderValueChangedEvent {
     pub entity: Entity,
@@ -8,5 +14,26 @@ pub struct SliderValueChangedEvent {
     pub previous_value: f32,
     pub new_value: f32,
     pub orientation: SliderOrientation,
+    pub preview: bool,
+    pub kind: SliderChangeKind,
 }
 
+#[derive(Event, Debug, Clone)]
+pub struct SliderCommittedEvent {
+    pub entity: Entity,
+    pub handle_entity: Entity,
+    pub start_value: f32,
+    pub final_value: f32,
+    pub orientation: SliderOrientation,
+}
+
+
+#[derive(Event, Debug, Clone)]
+pub struct SliderRangeChangedEvent {
+    pub entity: Entity,
+    pub previous_low: f32,
+    pub previous_high: f32,
+    pub new_low: f32,
+    pub new_high: f32,
+    pub orientation: SliderOrientation,
+}
@@ -1,37 +1,78 @@
 use bevy::prelude::*;
+use crate::binding::{WidgetBindingPlugin, WidgetBindingSystem};
+use crate::focus::{WidgetFocusPlugin, WidgetFocusSystem};
+use crate::input::WidgetInputPlugin;
+use crate::label::WidgetLabelPlugin;
+use crate::theme::WidgetThemePlugin;
+use crate::WidgetAppExt;
 
 pub mod components;
 pub mod resources;
 pub mod events;
 pub mod systems;
 pub mod builder;
+pub mod commands;
 
 pub use events::*;
 pub use resources::*;
 pub use systems::*;
-pub use builder::SliderBuilder;
+pub use builder::{RangeSliderBuilder, SliderBuilder};
+pub use commands::SliderCommandsExt;
 
 pub struct SliderPlugin;
 
 impl Plugin for SliderPlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugin_once(WidgetInputPlugin);
+        app.add_plugin_once(WidgetFocusPlugin);
+        app.add_plugin_once(WidgetThemePlugin);
+        app.add_plugin_once(WidgetBindingPlugin);
+        app.add_plugin_once(WidgetLabelPlugin);
+
         app
-            .init_resource::<SliderInputState>()
+            .init_resource::<SliderKeyboardSettings>()
             .add_event::<SliderValueChangedEvent>()
+            .add_event::<SliderCommittedEvent>()
+            .add_event::<SliderRangeChangedEvent>()
             .configure_sets(
                 Update,
                 (
                     SliderSystem::ProcessInput
                         .after(bevy::input::InputSystem)
+                        .after(WidgetFocusSystem)
                         .before(bevy::ui::UiSystem::Layout),
                     SliderSystem::UpdateVisuals.after(SliderSystem::ProcessInput),
                 )
                     .chain(),
             )
+            .configure_sets(
+                Update,
+                WidgetBindingSystem.after(SliderSystem::ProcessInput).before(SliderSystem::UpdateVisuals),
+            )
             .add_systems(Update, (
                 slider_drag_system.in_set(SliderSystem::ProcessInput),
+                slider_track_click_system.in_set(SliderSystem::ProcessInput),
+                slider_wheel_system.in_set(SliderSystem::ProcessInput),
                 slider_keyboard_input_system.in_set(SliderSystem::ProcessInput),
+                slider_buffer_changes_system
+                    .in_set(SliderSystem::ProcessInput)
+                    .after(slider_drag_system)
+                    .after(slider_track_click_system),
+                slider_emit_buffered_changes_system
+                    .in_set(SliderSystem::ProcessInput)
+                    .after(slider_buffer_changes_system),
+                range_slider_drag_system.in_set(SliderSystem::ProcessInput),
+                range_slider_keyboard_input_system.in_set(SliderSystem::ProcessInput),
+                slider_value_input_system
+                    .in_set(SliderSystem::ProcessInput)
+                    .before(slider_keyboard_input_system),
                 slider_update_visuals_system.in_set(SliderSystem::UpdateVisuals),
+                slider_value_input_visuals_system
+                    .in_set(SliderSystem::UpdateVisuals)
+                    .after(slider_update_visuals_system),
+                range_slider_update_visuals_system.in_set(SliderSystem::UpdateVisuals),
+                slider_theme_system.in_set(SliderSystem::UpdateVisuals),
+                slider_accessibility_system.in_set(SliderSystem::UpdateVisuals),
             ));
     }
 }
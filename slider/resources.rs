@@ -2,16 +2,16 @@ use bevy::prelude::*;
 use std::time::Duration;
 
 #[derive(Resource)]
-pub struct SliderInputState {
-    pub key_repeat_timer: Timer,
-    pub held_key: Option<KeyCode>,
+pub struct SliderKeyboardSettings {
+    pub repeat_delay: Duration,
+    pub repeat_interval: Duration,
 }
 
-impl Default for SliderInputState {
+impl Default for SliderKeyboardSettings {
     fn default() -> Self {
         Self {
-            key_repeat_timer: Timer::new(Duration::from_millis(300), TimerMode::Once),
-            held_key: None,
+            repeat_delay: Duration::from_millis(300),
+            repeat_interval: Duration::from_millis(50),
         }
     }
 }
\ No newline at end of file
@@ -1,6 +1,16 @@
+use accesskit::{Orientation, Role};
+use bevy::a11y::AccessibilityNode;
 use bevy::prelude::*;
-use super::{components::*, events::*, resources::SliderInputState};
-use std::time::Duration;
+use super::{components::*, events::*, resources::SliderKeyboardSettings};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::input::ButtonState;
+use bevy::ui::{ComputedNode, UiScale};
+use crate::accessibility::{set_label_links, sync_accessibility_node};
+use crate::focus::WidgetFocused;
+use crate::input::WidgetCursor;
+use crate::label::WidgetLabels;
+use crate::theme::ThemeLookup;
 
 fn format_value(value: f32, format: &ValueFormat) -> String {
     match format {
@@ -27,180 +37,586 @@ pub fn slider_drag_system(
             Entity,
             &Interaction,
             &ChildOf,
-            &GlobalTransform,
         ),
         With<SliderHandle>
     >,
-    mut q_sliders: Query<(&mut Slider, &SliderOptions)>,
-    q_tracks: Query<(&GlobalTransform, &ChildOf), With<SliderTrack>>,
-    q_nodes: Query<&Node>, // <- ADDED: Query for computed node sizes
-    mut evr_cursor: EventReader<CursorMoved>,
+    mut q_sliders: Query<(&mut Slider, &SliderOptions, &SliderEmitMode, Option<&SliderTicks>)>,
+    q_tracks: Query<(&ComputedNode, &GlobalTransform, &ChildOf), With<SliderTrack>>,
     mut evw_slider_change: EventWriter<SliderValueChangedEvent>,
     mouse_input: Res<ButtonInput<MouseButton>>,
-    cursor_position_res: Res<crate::user_interface::CursorPosition>,
+    ui_scale: Res<UiScale>,
+    cursor: Res<WidgetCursor>,
 ) {
-    // Get the latest cursor position from events or from the stored position
-    let cursor_position = evr_cursor.read().last().map(|ev| ev.position)
-        .or(cursor_position_res.0);
+    let cursor_position = cursor.position;
 
-    for (handle_entity, interaction, parent, handle_transform) in &mut q_handles {
-        if *interaction == Interaction::Pressed {
-            info!("Slider handle interaction detected: Pressed");
-        }
+    for (handle_entity, interaction, parent) in &mut q_handles {
         // Handle both initial press and continuous dragging while mouse button is held
         let should_update = match interaction {
-            Interaction::Pressed if mouse_input.pressed(MouseButton::Left) => {
-                true
-            },
-            Interaction::Hovered if mouse_input.pressed(MouseButton::Left) => {
-                true
-            },
+            Interaction::Pressed | Interaction::Hovered => mouse_input.pressed(MouseButton::Left),
             _ => false
         };
-        
-        if should_update {
-            info!("Processing slider drag with cursor position: {:?}", cursor_position);
-            info!("Handle entity: {:?}, Parent: {:?}", handle_entity, parent.parent());
+
+        let Some(cursor_pos) = cursor_position.filter(|_| should_update) else {
+            continue;
+        };
+
+        // Get the track entity (parent of handle)
+        let track_entity = parent.parent();
+        let Ok((track_node, track_transform, track_parent)) = q_tracks.get(track_entity) else {
+            trace!("Failed to get track layout for track entity: {:?}", track_entity);
+            continue;
+        };
+
+        // Get the slider entity (parent of track)
+        let slider_entity = track_parent.parent();
+        let Ok((mut slider, _options, emit_mode, ticks)) = q_sliders.get_mut(slider_entity) else {
+            trace!("Failed to get slider component for slider entity: {:?}", slider_entity);
+            continue;
+        };
+
+        let (track_center, track_size) = track_geometry(
+            track_node.size(),
+            track_transform.translation().truncate(),
+            track_node.inverse_scale_factor(),
+            ui_scale.0,
+        );
+        let clamped_new_value = drag_target_value(cursor_pos, track_center, track_size, &slider, ticks);
+
+        if (clamped_new_value - slider.value).abs() > f32::EPSILON {
+            let previous_value = slider.value;
+            slider.value = clamped_new_value; // Update the actual slider value
+
+            commands.entity(slider_entity).insert(SliderNeedsVisualUpdate);
+
+            evw_slider_change.write(SliderValueChangedEvent {
+                entity: slider_entity,
+                handle_entity,
+                previous_value,
+                new_value: clamped_new_value,
+                orientation: slider.orientation,
+                preview: *emit_mode == SliderEmitMode::OnRelease,
+                kind: SliderChangeKind::User,
+            });
         }
-        
-        if should_update && cursor_position.is_some() {
-            let cursor_pos = cursor_position.unwrap();
-            // Get the track entity (parent of handle)
-            let track_entity = parent.parent();
-            if let Ok(track_node) = q_nodes.get(track_entity) {
-                if let Ok((track_transform, track_parent)) = q_tracks.get(track_entity) {
-                    // Get the slider entity (parent of track)
-                    let slider_entity = track_parent.parent();
-                    if let Ok((mut slider, options)) = q_sliders.get_mut(slider_entity) {
-                        info!("Found slider components - proceeding with value calculation");
-                        // Compute size from Node - get actual computed size
-                        // For now, use default slider dimensions since computed size isn't working correctly
-                        let track_size = Vec2::new(200.0, 24.0); // TODO: Get actual computed size
-                        info!("Using fallback track size: {:?}", track_size);
-                        info!("Track size: {:?}, Track transform: {:?}", track_size, track_transform.translation());
-                        let new_value = cursor_position_to_value(
-                            cursor_pos,
-                            track_transform,
-                            track_size,
-                            &slider,
-                        );
-
-                    let stepped_new_value = apply_step(new_value, slider.min, slider.step);
-                    let clamped_new_value = stepped_new_value.clamp(slider.min, slider.max);
-
-                    info!("Slider value calculation: old={}, new={}, clamped={}", slider.value, new_value, clamped_new_value);
-
-                    if (clamped_new_value - slider.value).abs() > f32::EPSILON {
-                        let previous_value = slider.value;
-                        slider.value = clamped_new_value; // Update the actual slider value
-                        
-                        commands.entity(slider_entity).insert(SliderNeedsVisualUpdate);
-                        info!("Added SliderNeedsVisualUpdate to entity: {:?}", slider_entity);
-                        
-                        info!("Sending slider value changed event: {} -> {}", previous_value, clamped_new_value);
-                            evw_slider_change.write(SliderValueChangedEvent {
-                                entity: slider_entity,
-                                handle_entity,
-                                previous_value,
-                                new_value: clamped_new_value,
-                                orientation: slider.orientation,
-                            });
-                        }
+    }
+}
+
+pub fn slider_track_click_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<SliderKeyboardSettings>,
+    q_tracks: Query<(&Interaction, &ComputedNode, &GlobalTransform, &ChildOf), With<SliderTrack>>,
+    q_handles: Query<&Interaction, With<SliderHandle>>,
+    mut q_sliders: Query<(
+        &mut Slider,
+        &SliderEmitMode,
+        &SliderTrackClick,
+        &mut SliderTrackRepeat,
+        Option<&SliderTicks>,
+    )>,
+    mut evw_slider_change: EventWriter<SliderValueChangedEvent>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    ui_scale: Res<UiScale>,
+    cursor: Res<WidgetCursor>,
+) {
+    let cursor_position = cursor.position;
+
+    for (interaction, track_node, track_transform, track_parent) in &q_tracks {
+        let slider_entity = track_parent.parent();
+        let Ok((mut slider, emit_mode, track_click, mut repeat, ticks)) = q_sliders.get_mut(slider_entity) else {
+            continue;
+        };
+
+        if *interaction != Interaction::Pressed || !mouse_input.pressed(MouseButton::Left) {
+            repeat.timer = Timer::default();
+            continue;
+        }
+        // Presses on the handle fall through to the track; the drag system owns those
+        if matches!(q_handles.get(slider.handle_entity), Ok(Interaction::Pressed)) {
+            continue;
+        }
+        let Some(cursor_pos) = cursor_position else {
+            continue;
+        };
+
+        let (track_center, track_size) = track_geometry(
+            track_node.size(),
+            track_transform.translation().truncate(),
+            track_node.inverse_scale_factor(),
+            ui_scale.0,
+        );
+        let cursor_value = drag_target_value(cursor_pos, track_center, track_size, &slider, ticks);
+
+        let new_value = match track_click {
+            SliderTrackClick::Ignore => continue,
+            SliderTrackClick::JumpToCursor => cursor_value,
+            SliderTrackClick::PageTowardCursor => {
+                let fire = if mouse_input.just_pressed(MouseButton::Left) {
+                    repeat.timer = Timer::new(settings.repeat_delay, TimerMode::Once);
+                    true
+                } else {
+                    repeat.timer.tick(time.delta());
+                    if repeat.timer.just_finished() {
+                        repeat.timer = Timer::new(settings.repeat_interval, TimerMode::Once);
+                        true
                     } else {
-                        info!("Failed to get slider component for slider entity: {:?}", slider_entity);
+                        false
                     }
-                } else {
-                    info!("Failed to get track transform for track entity: {:?}", track_entity);
+                };
+                if !fire {
+                    continue;
                 }
-            } else {
-                info!("Failed to get track node for track entity: {:?}", track_entity);
+
+                let direction = (cursor_value - slider.value).signum();
+                let paged = apply_step(step_target(&slider, direction, true), slider.min, slider.step);
+                // Stop at the cursor rather than overshooting it
+                if direction > 0.0 { paged.min(cursor_value) } else { paged.max(cursor_value) }
+            }
+        }
+        .clamp(slider.min, slider.max);
+
+        if (new_value - slider.value).abs() > f32::EPSILON {
+            let previous_value = slider.value;
+            slider.value = new_value;
+
+            commands.entity(slider_entity).insert(SliderNeedsVisualUpdate);
+
+            evw_slider_change.write(SliderValueChangedEvent {
+                entity: slider_entity,
+                handle_entity: slider.handle_entity,
+                previous_value,
+                new_value,
+                orientation: slider.orientation,
+                preview: *emit_mode == SliderEmitMode::OnRelease,
+                kind: SliderChangeKind::User,
+            });
+        }
+    }
+}
+
+pub fn slider_wheel_system(
+    mut commands: Commands,
+    mut evr_wheel: EventReader<MouseWheel>,
+    keys: Res<ButtonInput<KeyCode>>,
+    q_tracks: Query<(&Interaction, &ChildOf), With<SliderTrack>>,
+    q_handles: Query<&Interaction, With<SliderHandle>>,
+    mut q_sliders: Query<(&mut Slider, &SliderEmitMode, &SliderWheel)>,
+    mut evw_slider_change: EventWriter<SliderValueChangedEvent>,
+    mut evw_slider_commit: EventWriter<SliderCommittedEvent>,
+) {
+    let scroll: f32 = evr_wheel
+        .read()
+        .map(|ev| match ev.unit {
+            MouseScrollUnit::Line => ev.y,
+            // Roughly one notch per 40px of smooth scrolling
+            MouseScrollUnit::Pixel => ev.y / 40.0,
+        })
+        .sum();
+    if scroll.abs() <= f32::EPSILON {
+        return;
+    }
+
+    for (interaction, track_parent) in &q_tracks {
+        let slider_entity = track_parent.parent();
+        let Ok((mut slider, emit_mode, wheel)) = q_sliders.get_mut(slider_entity) else {
+            continue;
+        };
+
+        let handle_hovered = !matches!(q_handles.get(slider.handle_entity), Ok(Interaction::None) | Err(_));
+        if *interaction == Interaction::None && !handle_hovered {
+            continue;
+        }
+
+        let fine = keys.any_pressed(wheel.fine_modifiers.iter().copied());
+        let amount = scroll.signum() * if fine { wheel.fine_factor } else { 1.0 };
+        let target = step_target(&slider, amount, false);
+        // Rounding a fine move back to the step would undo it
+        let new_value = if fine { target } else { apply_step(target, slider.min, slider.step) }
+            .clamp(slider.min, slider.max);
+
+        if (new_value - slider.value).abs() > f32::EPSILON {
+            let previous_value = slider.value;
+            slider.value = new_value;
+
+            evw_slider_change.write(SliderValueChangedEvent {
+                entity: slider_entity,
+                handle_entity: slider.handle_entity,
+                previous_value,
+                new_value,
+                orientation: slider.orientation,
+                preview: false,
+                kind: SliderChangeKind::User,
+            });
+
+            // Wheel notches are discrete, so they commit straight away
+            if *emit_mode == SliderEmitMode::OnRelease {
+                evw_slider_commit.write(SliderCommittedEvent {
+                    entity: slider_entity,
+                    handle_entity: slider.handle_entity,
+                    start_value: previous_value,
+                    final_value: new_value,
+                    orientation: slider.orientation,
+                });
             }
+
+            commands.entity(slider_entity).insert(SliderNeedsVisualUpdate);
         }
     }
 }
 
+const SLIDER_STEP_KEYS: [KeyCode; 6] = [
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowUp,
+    KeyCode::PageDown,
+    KeyCode::PageUp,
+];
+
 pub fn slider_keyboard_input_system(
     time: Res<Time>,
-    mut input_state: ResMut<SliderInputState>,
+    settings: Res<SliderKeyboardSettings>,
     keys: Res<ButtonInput<KeyCode>>,
-    mut q_sliders: Query<(Entity, &mut Slider, &SliderOptions)>,
+    mut q_sliders: Query<
+        (Entity, &mut Slider, &mut SliderKeyRepeat, &SliderEmitMode),
+        With<WidgetFocused>
+    >,
+    q_editing: Query<&SliderValueInput, With<SliderValueEditing>>,
     mut evw_slider_change: EventWriter<SliderValueChangedEvent>,
+    mut evw_slider_commit: EventWriter<SliderCommittedEvent>,
     mut commands: Commands,
 ) {
-    let mut step_direction = 0.0;
-
-    for &key in [KeyCode::ArrowLeft, KeyCode::ArrowRight, KeyCode::ArrowDown, KeyCode::ArrowUp].iter() {
-        if keys.just_pressed(key) {
-            input_state.held_key = Some(key);
-            input_state.key_repeat_timer.reset();
-            step_direction = match key {
-                KeyCode::ArrowRight | KeyCode::ArrowUp => 1.0,
-                KeyCode::ArrowLeft | KeyCode::ArrowDown => -1.0,
-                _ => 0.0,
-            };
-            break;
+    for (entity, mut slider, mut repeat, emit_mode) in &mut q_sliders {
+        // Typing into the value field shouldn't also nudge the slider
+        if q_editing.iter().any(|input| input.slider == entity) {
+            continue;
         }
-    }
 
-    if let Some(held_key) = input_state.held_key {
-        if keys.pressed(held_key) {
-            input_state.key_repeat_timer.tick(time.delta());
-            if input_state.key_repeat_timer.just_finished() {
-                step_direction = match held_key {
-                    KeyCode::ArrowRight | KeyCode::ArrowUp => 1.0,
-                    KeyCode::ArrowLeft | KeyCode::ArrowDown => -1.0,
-                    _ => 0.0,
-                };
-                input_state.key_repeat_timer = Timer::new(Duration::from_millis(50), TimerMode::Once);
-                input_state.key_repeat_timer.reset();
+        let mut target_value = None;
+
+        if keys.just_pressed(KeyCode::Home) {
+            target_value = Some(slider.min);
+        } else if keys.just_pressed(KeyCode::End) {
+            target_value = Some(slider.max);
+        }
+
+        if let Some(&key) = SLIDER_STEP_KEYS.iter().find(|key| keys.just_pressed(**key)) {
+            repeat.held_key = Some(key);
+            repeat.timer = Timer::new(settings.repeat_delay, TimerMode::Once);
+            target_value = Some(key_target(key, &slider));
+        } else if let Some(held_key) = repeat.held_key {
+            if keys.pressed(held_key) {
+                repeat.timer.tick(time.delta());
+                if repeat.timer.just_finished() {
+                    target_value = Some(key_target(held_key, &slider));
+                    repeat.timer = Timer::new(settings.repeat_interval, TimerMode::Once);
+                }
+            } else {
+                repeat.held_key = None;
             }
-        } else {
-            input_state.held_key = None;
         }
-    }
 
-    if step_direction != 0.0 {
-        for (entity, mut slider, options) in &mut q_sliders {
-            let step_amount = slider.step.unwrap_or((slider.max - slider.min) * 0.05);
-            let new_value = apply_step(
-                slider.value + (step_direction * step_amount),
-                slider.min,
-                slider.step,
-            ).clamp(slider.min, slider.max);
+        let Some(target_value) = target_value else {
+            continue;
+        };
+
+        let new_value = apply_step(target_value, slider.min, slider.step)
+            .clamp(slider.min, slider.max);
+
+        if (new_value - slider.value).abs() > f32::EPSILON {
+            let previous_value = slider.value;
+            slider.value = new_value;
 
-            if (new_value - slider.value).abs() > f32::EPSILON {
-                let previous_value = slider.value;
-                slider.value = new_value;
+            evw_slider_change.write(SliderValueChangedEvent {
+                entity,
+                handle_entity: slider.handle_entity,
+                previous_value,
+                new_value,
+                orientation: slider.orientation,
+                preview: false,
+                kind: SliderChangeKind::User,
+            });
 
-                evw_slider_change.write(SliderValueChangedEvent {
+            // Key presses are discrete, so they commit straight away
+            if *emit_mode == SliderEmitMode::OnRelease {
+                evw_slider_commit.write(SliderCommittedEvent {
                     entity,
-                    handle_entity: slider.handle_entity, // Would need to be looked up
-                    previous_value,
-                    new_value,
+                    handle_entity: slider.handle_entity,
+                    start_value: previous_value,
+                    final_value: new_value,
+                    orientation: slider.orientation,
+                });
+            }
+
+            commands.entity(entity).insert(SliderNeedsVisualUpdate);
+        }
+    }
+}
+
+fn key_target(key: KeyCode, slider: &Slider) -> f32 {
+    let direction = match key {
+        KeyCode::ArrowRight | KeyCode::ArrowUp | KeyCode::PageUp => 1.0,
+        KeyCode::ArrowLeft | KeyCode::ArrowDown | KeyCode::PageDown => -1.0,
+        _ => return slider.value,
+    };
+    step_target(slider, direction, matches!(key, KeyCode::PageUp | KeyCode::PageDown))
+}
+
+/// Value one step (or page) away from the current one. `amount` scales the
+/// step, so its sign picks the direction and fractions give fine control.
+///
+/// Steps are taken along the track, like drags and clicks, so non-linear
+/// scales feel even along their whole length. An explicit step covers the
+/// same share of the track it would on a linear scale, but always moves the
+/// value by at least that step so the compressed end of a scale can't
+/// swallow a key press.
+fn step_target(slider: &Slider, amount: f32, is_page: bool) -> f32 {
+    let span = slider.max - slider.min;
+    if span.abs() <= f32::EPSILON {
+        return slider.value;
+    }
+    let value_step = match (is_page, slider.page_step, slider.step) {
+        (true, Some(page_step), _) => Some(page_step),
+        (true, None, Some(step)) => Some(step * 10.0),
+        (false, _, Some(step)) => Some(step),
+        _ => None,
+    };
+    let position_step = match value_step {
+        Some(value_step) => value_step / span,
+        None if is_page => 0.5,
+        None => 0.05,
+    };
+    let position = slider.scale.position_from_value(slider.value, slider.min, slider.max);
+    let target = slider.scale.value_from_position(position + amount * position_step, slider.min, slider.max);
+    match value_step {
+        Some(value_step) if (target - slider.value).abs() < (amount * value_step).abs() => {
+            (slider.value + amount * value_step).clamp(slider.min, slider.max)
+        }
+        _ => target,
+    }
+}
+
+pub fn slider_value_input_system(
+    mut commands: Commands,
+    mut q_inputs: Query<(
+        Entity,
+        &Interaction,
+        &SliderValueInput,
+        &Text,
+        Option<&mut SliderValueEditing>,
+    )>,
+    mut q_sliders: Query<(&mut Slider, &SliderOptions, &SliderEmitMode)>,
+    mut evr_keys: EventReader<KeyboardInput>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut evw_slider_change: EventWriter<SliderValueChangedEvent>,
+    mut evw_slider_commit: EventWriter<SliderCommittedEvent>,
+) {
+    let key_events: Vec<KeyboardInput> = evr_keys
+        .read()
+        .filter(|ev| ev.state == ButtonState::Pressed)
+        .cloned()
+        .collect();
+
+    for (input_entity, interaction, input, text, editing) in &mut q_inputs {
+        let Some(mut editing) = editing else {
+            if *interaction == Interaction::Pressed {
+                commands.entity(input_entity).insert(SliderValueEditing {
+                    buffer: text.0.clone(),
+                    replace_on_type: true,
+                });
+            }
+            continue;
+        };
+
+        let mut submit = false;
+        let mut cancel = false;
+        for event in &key_events {
+            match &event.logical_key {
+                Key::Enter => submit = true,
+                Key::Escape => cancel = true,
+                Key::Backspace => {
+                    if editing.replace_on_type {
+                        editing.buffer.clear();
+                    } else {
+                        editing.buffer.pop();
+                    }
+                    editing.replace_on_type = false;
+                }
+                Key::Character(chars) => {
+                    if editing.replace_on_type {
+                        editing.buffer.clear();
+                        editing.replace_on_type = false;
+                    }
+                    editing.buffer.extend(chars.chars().filter(|c| !c.is_control()));
+                }
+                _ => {}
+            }
+        }
+
+        // Clicking anywhere else commits, like leaving a form field
+        if mouse_input.just_pressed(MouseButton::Left) && *interaction != Interaction::Pressed {
+            submit = true;
+        }
+
+        if cancel {
+            commands.entity(input_entity)
+                .remove::<SliderValueEditing>()
+                .remove::<SliderValueInputInvalid>();
+            commands.entity(input.slider).insert(SliderNeedsVisualUpdate);
+            continue;
+        }
+
+        let Ok((mut slider, options, emit_mode)) = q_sliders.get_mut(input.slider) else {
+            continue;
+        };
+
+        if !submit {
+            if editing.is_changed() {
+                commands.entity(input_entity).remove::<SliderValueInputInvalid>();
+            }
+            continue;
+        }
+
+        let Some(parsed) = options.format.parse(&editing.buffer, options.parser.as_ref()) else {
+            // Keep editing so the user can fix the typo
+            commands.entity(input_entity).insert(SliderValueInputInvalid);
+            continue;
+        };
+
+        commands.entity(input_entity)
+            .remove::<SliderValueEditing>()
+            .remove::<SliderValueInputInvalid>();
+        commands.entity(input.slider).insert(SliderNeedsVisualUpdate);
+
+        let new_value = apply_step(parsed.clamp(slider.min, slider.max), slider.min, slider.step)
+            .clamp(slider.min, slider.max);
+        if (new_value - slider.value).abs() > f32::EPSILON {
+            let previous_value = slider.value;
+            slider.value = new_value;
+
+            evw_slider_change.write(SliderValueChangedEvent {
+                entity: input.slider,
+                handle_entity: slider.handle_entity,
+                previous_value,
+                new_value,
+                orientation: slider.orientation,
+                preview: false,
+                kind: SliderChangeKind::User,
+            });
+
+            if *emit_mode == SliderEmitMode::OnRelease {
+                evw_slider_commit.write(SliderCommittedEvent {
+                    entity: input.slider,
+                    handle_entity: slider.handle_entity,
+                    start_value: previous_value,
+                    final_value: new_value,
                     orientation: slider.orientation,
                 });
-                
-                commands.entity(entity).insert(SliderNeedsVisualUpdate);
             }
         }
     }
 }
 
+pub fn slider_value_input_visuals_system(
+    themes: ThemeLookup,
+    mut q_inputs: Query<
+        (
+            Entity,
+            &mut Text,
+            &mut TextColor,
+            &mut BorderColor,
+            Option<Ref<SliderValueEditing>>,
+            Has<SliderValueInputInvalid>,
+        ),
+        With<SliderValueInput>
+    >,
+) {
+    for (entity, mut text, mut text_color, mut border_color, editing, invalid) in &mut q_inputs {
+        if let Some(editing) = editing.as_ref().filter(|editing| editing.is_changed()) {
+            text.0 = format!("{}|", editing.buffer);
+        }
+
+        let palette = &themes.get(entity).palette;
+        text_color.set_if_neq(TextColor(if invalid {
+            palette.error
+        } else {
+            palette.text
+        }));
+        border_color.set_if_neq(BorderColor(match (invalid, editing.is_some()) {
+            (true, _) => palette.error,
+            (false, true) => palette.accent,
+            (false, false) => palette.border,
+        }));
+    }
+}
+
+/// Applies the theme to newly spawned slider parts, and to every slider
+/// part when the theme changes.
+pub fn slider_theme_system(
+    themes: ThemeLookup,
+    mut q_backgrounds: Query<
+        (Entity, &mut BackgroundColor, Has<SliderFill>, Has<SliderTickMark>),
+        Or<(With<SliderTrack>, With<SliderFill>, With<SliderHandle>, With<RangeSliderHandle>, With<SliderTickMark>)>
+    >,
+    q_added_backgrounds: Query<
+        (),
+        Or<(Added<SliderTrack>, Added<SliderFill>, Added<SliderHandle>, Added<RangeSliderHandle>, Added<SliderTickMark>)>
+    >,
+    mut q_text: Query<
+        (Entity, &mut TextColor, &mut TextFont, Has<SliderTickLabel>, Has<SliderValueInput>),
+        Or<(With<SliderValueText>, With<SliderValueInput>, With<SliderTickLabel>)>
+    >,
+    q_added_text: Query<(), Or<(Added<SliderValueText>, Added<SliderValueInput>, Added<SliderTickLabel>)>>,
+    q_handles: Query<(), Or<(With<SliderHandle>, With<RangeSliderHandle>)>>,
+) {
+    let restyle_all = themes.changed();
+
+    for (entity, mut color, is_fill, is_tick) in &mut q_backgrounds {
+        if !restyle_all && !q_added_backgrounds.contains(entity) {
+            continue;
+        }
+        let palette = &themes.get(entity).palette;
+        color.0 = if is_fill {
+            palette.accent
+        } else if is_tick {
+            palette.border
+        } else if q_handles.contains(entity) {
+            palette.handle
+        } else {
+            palette.track
+        };
+    }
+
+    for (entity, mut color, mut font, is_label, is_input) in &mut q_text {
+        if !restyle_all && !q_added_text.contains(entity) {
+            continue;
+        }
+        let theme = themes.get(entity);
+        let size = if is_label { theme.typography.label_size } else { theme.typography.value_size };
+        *font = theme.typography.text_font(size);
+        // Value fields are colored by their own state
+        if !is_input {
+            color.0 = theme.palette.text;
+        }
+    }
+}
+
 pub fn slider_update_visuals_system(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
     mut q_sliders: Query<
         (Entity, &Slider, &SliderOptions, &Children),
         With<SliderNeedsVisualUpdate>
     >,
     mut q_handles: Query<&mut Node, (With<SliderHandle>, Without<SliderFill>)>,
     mut q_fills: Query<&mut Node, (With<SliderFill>, Without<SliderHandle>)>,
-    mut q_text: Query<&mut Text, With<SliderValueText>>,
+    mut q_text: Query<
+        &mut Text,
+        Or<(With<SliderValueText>, (With<SliderValueInput>, Without<SliderValueEditing>))>
+    >,
     q_children: Query<&Children>,
 ) {
     for (slider_entity, slider, options, children) in &mut q_sliders {
-        let normalized_value = (slider.value - slider.min) / (slider.max - slider.min);
-        info!("Visual update for slider {:?}: value={}, normalized={}", slider_entity, slider.value, normalized_value);
+        let normalized_value = slider.scale.position_from_value(slider.value, slider.min, slider.max);
         
         // Look through direct children and their children for handles
         for child in children.iter() {
@@ -209,12 +625,10 @@ pub fn slider_update_visuals_system(
                 match slider.orientation {
                     SliderOrientation::Horizontal => {
                         let new_left = normalized_value * 100.0;
-                        info!("Updating handle position: left={}%", new_left);
                         handle_style.left = Val::Percent(new_left);
                     }
                     SliderOrientation::Vertical => {
                         let new_bottom = normalized_value * 100.0;
-                        info!("Updating handle position: bottom={}%", new_bottom);
                         handle_style.bottom = Val::Percent(new_bottom);
                     }
                 }
@@ -226,12 +640,10 @@ pub fn slider_update_visuals_system(
                         match slider.orientation {
                             SliderOrientation::Horizontal => {
                                 let new_left = normalized_value * 100.0;
-                                info!("Updating handle position (grandchild): left={}%", new_left);
                                 handle_style.left = Val::Percent(new_left);
                             }
                             SliderOrientation::Vertical => {
                                 let new_bottom = normalized_value * 100.0;
-                                info!("Updating handle position (grandchild): bottom={}%", new_bottom);
                                 handle_style.bottom = Val::Percent(new_bottom);
                             }
                         }
@@ -259,7 +671,240 @@ pub fn slider_update_visuals_system(
     }
 }
 
-fn apply_step(value: f32, min: f32, step: Option<f32>) -> f32 {
+pub fn range_slider_drag_system(
+    mut commands: Commands,
+    q_handles: Query<(&Interaction, &RangeSliderHandle, &ChildOf)>,
+    mut q_ranges: Query<(&mut RangeSlider, &mut RangeSliderDrag)>,
+    q_tracks: Query<(&ComputedNode, &GlobalTransform, &ChildOf), With<SliderTrack>>,
+    mut evw_range_change: EventWriter<SliderRangeChangedEvent>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    ui_scale: Res<UiScale>,
+    cursor: Res<WidgetCursor>,
+) {
+    let cursor_position = cursor.position;
+
+    for (interaction, range_handle, parent) in &q_handles {
+        let Ok((track_node, track_transform, track_parent)) = q_tracks.get(parent.parent()) else {
+            continue;
+        };
+        let range_entity = track_parent.parent();
+        let Ok((mut range, mut drag)) = q_ranges.get_mut(range_entity) else {
+            continue;
+        };
+
+        // The pressed handle owns the drag until release so the handles can't swap mid-drag
+        if *interaction == Interaction::Pressed && drag.active.is_none() {
+            drag.active = Some(range_handle.0);
+        }
+        if !mouse_input.pressed(MouseButton::Left) {
+            drag.active = None;
+            continue;
+        }
+        if drag.active != Some(range_handle.0) {
+            continue;
+        }
+        let Some(cursor_pos) = cursor_position else {
+            continue;
+        };
+
+        let (track_center, track_size) = track_geometry(
+            track_node.size(),
+            track_transform.translation().truncate(),
+            track_node.inverse_scale_factor(),
+            ui_scale.0,
+        );
+        let fraction = cursor_position_to_fraction(cursor_pos, track_center, track_size, range.orientation);
+        let raw_value = range.min + fraction * (range.max - range.min);
+        let stepped = apply_step(raw_value, range.min, range.step);
+
+        // Stacked handles (only possible with a zero gap) can each move one way only,
+        // so whichever handle can follow the cursor takes over the drag
+        let mut handle = range_handle.0;
+        if (range.high - range.low).abs() <= f32::EPSILON && (stepped - range.low).abs() > f32::EPSILON {
+            handle = if stepped > range.low { RangeHandle::High } else { RangeHandle::Low };
+            drag.active = Some(handle);
+        }
+        let new_value = range.constrain(handle, stepped);
+
+        let (previous_low, previous_high) = (range.low, range.high);
+        match handle {
+            RangeHandle::Low => range.low = new_value,
+            RangeHandle::High => range.high = new_value,
+        }
+
+        if (range.low - previous_low).abs() > f32::EPSILON
+            || (range.high - previous_high).abs() > f32::EPSILON
+        {
+            commands.entity(range_entity).insert(SliderNeedsVisualUpdate);
+
+            evw_range_change.write(SliderRangeChangedEvent {
+                entity: range_entity,
+                previous_low,
+                previous_high,
+                new_low: range.low,
+                new_high: range.high,
+                orientation: range.orientation,
+            });
+        }
+    }
+}
+
+pub fn range_slider_keyboard_input_system(
+    time: Res<Time>,
+    settings: Res<SliderKeyboardSettings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut q_handles: Query<(&RangeSliderHandle, &ChildOf, &mut SliderKeyRepeat), With<WidgetFocused>>,
+    q_tracks: Query<&ChildOf, With<SliderTrack>>,
+    mut q_ranges: Query<&mut RangeSlider>,
+    mut evw_range_change: EventWriter<SliderRangeChangedEvent>,
+    mut commands: Commands,
+) {
+    for (range_handle, parent, mut repeat) in &mut q_handles {
+        let Ok(track_parent) = q_tracks.get(parent.parent()) else {
+            continue;
+        };
+        let range_entity = track_parent.parent();
+        let Ok(mut range) = q_ranges.get_mut(range_entity) else {
+            continue;
+        };
+
+        let mut target_value = None;
+
+        if keys.just_pressed(KeyCode::Home) {
+            target_value = Some(range.min);
+        } else if keys.just_pressed(KeyCode::End) {
+            target_value = Some(range.max);
+        }
+
+        if let Some(&key) = SLIDER_STEP_KEYS.iter().find(|key| keys.just_pressed(**key)) {
+            repeat.held_key = Some(key);
+            repeat.timer = Timer::new(settings.repeat_delay, TimerMode::Once);
+            target_value = Some(range_key_target(key, &range, range_handle.0));
+        } else if let Some(held_key) = repeat.held_key {
+            if keys.pressed(held_key) {
+                repeat.timer.tick(time.delta());
+                if repeat.timer.just_finished() {
+                    target_value = Some(range_key_target(held_key, &range, range_handle.0));
+                    repeat.timer = Timer::new(settings.repeat_interval, TimerMode::Once);
+                }
+            } else {
+                repeat.held_key = None;
+            }
+        }
+
+        let Some(target_value) = target_value else {
+            continue;
+        };
+
+        // Home/End stop at the other thumb rather than passing it
+        let new_value = range.constrain(
+            range_handle.0,
+            apply_step(target_value, range.min, range.step),
+        );
+
+        let (previous_low, previous_high) = (range.low, range.high);
+        match range_handle.0 {
+            RangeHandle::Low => range.low = new_value,
+            RangeHandle::High => range.high = new_value,
+        }
+
+        if (range.low - previous_low).abs() > f32::EPSILON
+            || (range.high - previous_high).abs() > f32::EPSILON
+        {
+            commands.entity(range_entity).insert(SliderNeedsVisualUpdate);
+
+            evw_range_change.write(SliderRangeChangedEvent {
+                entity: range_entity,
+                previous_low,
+                previous_high,
+                new_low: range.low,
+                new_high: range.high,
+                orientation: range.orientation,
+            });
+        }
+    }
+}
+
+fn range_key_target(key: KeyCode, range: &RangeSlider, handle: RangeHandle) -> f32 {
+    let value = match handle {
+        RangeHandle::Low => range.low,
+        RangeHandle::High => range.high,
+    };
+    let direction = match key {
+        KeyCode::ArrowRight | KeyCode::ArrowUp | KeyCode::PageUp => 1.0,
+        KeyCode::ArrowLeft | KeyCode::ArrowDown | KeyCode::PageDown => -1.0,
+        _ => return value,
+    };
+    let is_page = matches!(key, KeyCode::PageUp | KeyCode::PageDown);
+    let step = match (is_page, range.step) {
+        (true, Some(step)) => step * 10.0,
+        (false, Some(step)) => step,
+        (true, None) => (range.max - range.min) * 0.5,
+        (false, None) => (range.max - range.min) * 0.05,
+    };
+    value + direction * step
+}
+
+pub fn range_slider_update_visuals_system(
+    mut commands: Commands,
+    q_ranges: Query<
+        (Entity, &RangeSlider, &SliderOptions, &Children),
+        With<SliderNeedsVisualUpdate>
+    >,
+    mut q_handles: Query<&mut Node, (With<RangeSliderHandle>, Without<SliderFill>)>,
+    mut q_fills: Query<&mut Node, (With<SliderFill>, Without<RangeSliderHandle>)>,
+    mut q_text: Query<&mut Text, With<SliderValueText>>,
+    q_children: Query<&Children>,
+) {
+    for (range_entity, range, options, children) in &q_ranges {
+        let span = (range.max - range.min).max(f32::EPSILON);
+        let low_percent = (range.low - range.min) / span * 100.0;
+        let high_percent = (range.high - range.min) / span * 100.0;
+
+        for (handle_entity, percent) in [(range.low_handle, low_percent), (range.high_handle, high_percent)] {
+            if let Ok(mut handle_style) = q_handles.get_mut(handle_entity) {
+                match range.orientation {
+                    SliderOrientation::Horizontal => handle_style.left = Val::Percent(percent),
+                    SliderOrientation::Vertical => handle_style.bottom = Val::Percent(percent),
+                }
+            }
+        }
+
+        for child in children.iter() {
+            // Fill lives on the track, so look one level down
+            for grandchild in q_children.get(child).into_iter().flat_map(|grandchildren| grandchildren.iter()) {
+                if let Ok(mut fill_style) = q_fills.get_mut(grandchild) {
+                    match range.orientation {
+                        SliderOrientation::Horizontal => {
+                            fill_style.left = Val::Percent(low_percent);
+                            fill_style.width = Val::Percent(high_percent - low_percent);
+                        }
+                        SliderOrientation::Vertical => {
+                            fill_style.bottom = Val::Percent(low_percent);
+                            fill_style.height = Val::Percent(high_percent - low_percent);
+                        }
+                    }
+                }
+            }
+
+            if let Ok(mut text) = q_text.get_mut(child) {
+                **text = if options.show_value {
+                    format!(
+                        "{} - {}",
+                        format_value(range.low, &options.format),
+                        format_value(range.high, &options.format),
+                    )
+                } else {
+                    String::new()
+                };
+            }
+        }
+
+        commands.entity(range_entity).remove::<SliderNeedsVisualUpdate>();
+    }
+}
+
+pub(crate) fn apply_step(value: f32, min: f32, step: Option<f32>) -> f32 {
     let Some(step) = step else {
         return value;
     };
@@ -267,50 +912,289 @@ fn apply_step(value: f32, min: f32, step: Option<f32>) -> f32 {
     min + (steps * step)
 }
 
+/// Value under the cursor, snapped to a tick or rounded to the step.
+fn drag_target_value(
+    cursor_pos: Vec2,
+    track_center: Vec2,
+    track_size: Vec2,
+    slider: &Slider,
+    ticks: Option<&SliderTicks>,
+) -> f32 {
+    let new_value = cursor_position_to_value(cursor_pos, track_center, track_size, slider);
+    let track_length = match slider.orientation {
+        SliderOrientation::Horizontal => track_size.x,
+        SliderOrientation::Vertical => track_size.y,
+    };
+    ticks
+        .and_then(|ticks| snap_to_tick(new_value, slider, ticks, track_length))
+        .unwrap_or_else(|| apply_step(new_value, slider.min, slider.step))
+        .clamp(slider.min, slider.max)
+}
+
+/// Returns the nearest tick's value if it lies within the ticks' snap
+/// distance of `value`, measured in logical pixels along the track.
+fn snap_to_tick(value: f32, slider: &Slider, ticks: &SliderTicks, track_length: f32) -> Option<f32> {
+    let snap_distance = ticks.snap_distance?;
+    let position = slider.scale.position_from_value(value, slider.min, slider.max);
+    ticks
+        .ticks
+        .iter()
+        .map(|tick| {
+            let tick_position = slider.scale.position_from_value(tick.value, slider.min, slider.max);
+            (tick.value, (tick_position - position).abs() * track_length)
+        })
+        .filter(|(_, distance)| *distance <= snap_distance)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(tick_value, _)| tick_value)
+}
+
+/// Converts a track's laid-out size and translation (physical pixels) into
+/// logical pixels, the same space `CursorMoved` reports positions in.
+///
+/// `inverse_scale_factor` comes from the track's `ComputedNode` and already
+/// folds in the window scale factor and `UiScale`; multiplying `UiScale` back
+/// in leaves only the window scale factor, which is what the cursor uses.
+pub(crate) fn track_geometry(
+    computed_size: Vec2,
+    translation: Vec2,
+    inverse_scale_factor: f32,
+    ui_scale: f32,
+) -> (Vec2, Vec2) {
+    let to_logical = inverse_scale_factor * ui_scale;
+    (translation * to_logical, computed_size * to_logical)
+}
+
 fn cursor_position_to_value(
     cursor_pos: Vec2,
-    track_global_transform: &GlobalTransform,
-    track_size: Vec2, // <- Now takes computed Vec2 size
+    track_center: Vec2,
+    track_size: Vec2,
     slider: &Slider,
 ) -> f32 {
-    let track_center = track_global_transform.translation();
-    let relative_pos = match slider.orientation {
+    let fraction = cursor_position_to_fraction(cursor_pos, track_center, track_size, slider.orientation);
+    slider.scale.value_from_position(fraction, slider.min, slider.max)
+}
+
+fn cursor_position_to_fraction(
+    cursor_pos: Vec2,
+    track_center: Vec2,
+    track_size: Vec2,
+    orientation: SliderOrientation,
+) -> f32 {
+    let relative_pos = match orientation {
         SliderOrientation::Horizontal => {
-            (cursor_pos.x - (track_center.x - track_size.x / 2.0)) / track_size.x
+            (cursor_pos.x - (track_center.x - track_size.x / 2.0)) / track_size.x.max(f32::EPSILON)
         }
         SliderOrientation::Vertical => {
-            (cursor_pos.y - (track_center.y - track_size.y / 2.0)) / track_size.y
+            // UI space grows downwards but vertical sliders fill from the bottom
+            1.0 - (cursor_pos.y - (track_center.y - track_size.y / 2.0)) / track_size.y.max(f32::EPSILON)
         }
     };
-    slider.min + (relative_pos.clamp(0.0, 1.0) * (slider.max - slider.min))
+    relative_pos.clamp(0.0, 1.0)
 }
 
 pub fn slider_buffer_changes_system(
     mut commands: Commands,
     mut evr_slider_change: EventReader<SliderValueChangedEvent>,
-    q_sliders: Query<&SliderEmitMode>,
+    q_sliders: Query<(&SliderEmitMode, Has<SliderPendingChange>)>,
+    mut started: Local<Vec<Entity>>,
 ) {
-    for event in evr_slider_change.read() {
-        if let Ok(emit_mode) = q_sliders.get(event.entity) {
-            if matches!(emit_mode, SliderEmitMode::OnRelease) {
-                commands.entity(event.entity).insert(SliderPendingChange {
-                    value: event.new_value,
-                });
-                // Don't send the event downstream yet
-            }
+    started.clear();
+    for event in evr_slider_change.read().filter(|event| event.preview) {
+        let Ok((emit_mode, has_pending)) = q_sliders.get(event.entity) else {
+            continue;
+        };
+        // Only the first preview of a drag records where it started
+        if *emit_mode == SliderEmitMode::OnRelease && !has_pending && !started.contains(&event.entity) {
+            started.push(event.entity);
+            commands.entity(event.entity).insert(SliderPendingChange {
+                start_value: event.previous_value,
+            });
         }
     }
 }
 
 pub fn slider_emit_buffered_changes_system(
     mut commands: Commands,
-    mut q_sliders: Query<(Entity, &SliderPendingChange, &Interaction), Changed<Interaction>>,
-    mut evw_final_change: EventWriter<SliderValueChangedEvent>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    q_sliders: Query<(Entity, &Slider, &SliderPendingChange)>,
+    mut evw_final_change: EventWriter<SliderCommittedEvent>,
+) {
+    if mouse_input.pressed(MouseButton::Left) {
+        return;
+    }
+
+    for (entity, slider, pending_change) in &q_sliders {
+        evw_final_change.write(SliderCommittedEvent {
+            entity,
+            handle_entity: slider.handle_entity,
+            start_value: pending_change.start_value,
+            final_value: slider.value,
+            orientation: slider.orientation,
+        });
+        commands.entity(entity).remove::<SliderPendingChange>();
+    }
+}
+
+/// Publishes sliders and knobs as sliders with their range, step and the
+/// value as displayed, linked to any label and description.
+pub fn slider_accessibility_system(
+    mut commands: Commands,
+    mut q_sliders: Query<
+        (Entity, &Slider, &SliderOptions, Option<&WidgetLabels>, Option<&mut AccessibilityNode>),
+        Or<(Changed<Slider>, Changed<AccessibilityNode>)>
+    >,
 ) {
-    for (entity, pending_change, interaction) in &mut q_sliders {
-        if matches!(interaction, Interaction::None) {
-            // Note: This would need the full event data from the original slider
-            commands.entity(entity).remove::<SliderPendingChange>();
+    for (entity, slider, options, labels, node) in &mut q_sliders {
+        sync_accessibility_node(&mut commands, entity, node, Role::Slider, |node| {
+            node.set_numeric_value(slider.value as f64);
+            node.set_min_numeric_value(slider.min as f64);
+            node.set_max_numeric_value(slider.max as f64);
+            match slider.step {
+                Some(step) => node.set_numeric_value_step(step as f64),
+                None => node.clear_numeric_value_step(),
+            }
+            node.set_value(options.format.format(slider.value));
+            node.set_orientation(match slider.orientation {
+                SliderOrientation::Horizontal => Orientation::Horizontal,
+                SliderOrientation::Vertical => Orientation::Vertical,
+            });
+            set_label_links(node, labels);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slider(min: f32, max: f32, orientation: SliderOrientation) -> Slider {
+        Slider {
+            min,
+            max,
+            step: None,
+            orientation,
+            value: min,
+            page_step: None,
+            scale: SliderScale::Linear,
+            handle_entity: Entity::PLACEHOLDER,
         }
     }
-}
\ No newline at end of file
+
+    /// Sweeps a synthetic cursor from one end of the track to the other and
+    /// checks every sample lands on the matching fraction of the range.
+    fn sweep(slider: &Slider, center: Vec2, size: Vec2) {
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            let cursor = match slider.orientation {
+                SliderOrientation::Horizontal => {
+                    Vec2::new(center.x - size.x / 2.0 + size.x * t, center.y)
+                }
+                SliderOrientation::Vertical => {
+                    Vec2::new(center.x, center.y + size.y / 2.0 - size.y * t)
+                }
+            };
+            let value = cursor_position_to_value(cursor, center, size, slider);
+            let expected = slider.min + t * (slider.max - slider.min);
+            assert!(
+                (value - expected).abs() < 1e-3,
+                "size {size:?}, t {t}: expected {expected}, got {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn horizontal_drag_uses_track_size() {
+        let slider = slider(0.0, 100.0, SliderOrientation::Horizontal);
+        for size in [Vec2::new(200.0, 24.0), Vec2::new(80.0, 12.0), Vec2::new(640.0, 40.0)] {
+            sweep(&slider, Vec2::new(400.0, 300.0), size);
+        }
+    }
+
+    #[test]
+    fn vertical_drag_fills_from_bottom() {
+        let slider = slider(-1.0, 1.0, SliderOrientation::Vertical);
+        for size in [Vec2::new(24.0, 200.0), Vec2::new(16.0, 75.0), Vec2::new(32.0, 512.0)] {
+            sweep(&slider, Vec2::new(50.0, 250.0), size);
+        }
+    }
+
+    #[test]
+    fn cursor_outside_track_clamps() {
+        let slider = slider(0.0, 1.0, SliderOrientation::Horizontal);
+        let center = Vec2::new(100.0, 12.0);
+        let size = Vec2::new(150.0, 24.0);
+        assert_eq!(cursor_position_to_value(Vec2::new(-500.0, 12.0), center, size, &slider), 0.0);
+        assert_eq!(cursor_position_to_value(Vec2::new(900.0, 12.0), center, size, &slider), 1.0);
+    }
+
+    #[test]
+    fn drag_snaps_to_ticks_within_pixel_distance() {
+        let slider = slider(0.0, 100.0, SliderOrientation::Horizontal);
+        let ticks = SliderTicks {
+            ticks: vec![SliderTick::new(25.0), SliderTick::labeled(50.0, "half")],
+            snap_distance: Some(8.0),
+        };
+
+        // 200px track: 1 unit is 2px, so 47 is 6px from the 50 tick
+        assert_eq!(snap_to_tick(47.0, &slider, &ticks, 200.0), Some(50.0));
+        assert_eq!(snap_to_tick(38.0, &slider, &ticks, 200.0), None);
+        // The same offset on a 50px track is well inside the snap radius
+        assert_eq!(snap_to_tick(38.0, &slider, &ticks, 50.0), Some(50.0));
+    }
+
+    #[test]
+    fn typed_values_parse_through_format() {
+        assert_eq!(ValueFormat::Precision(2).parse(" 0.25 ", None), Some(0.25));
+        assert_eq!(ValueFormat::Percent(0).parse("40%", None), Some(0.4));
+        assert_eq!(ValueFormat::Percent(0).parse("40", None), Some(0.4));
+        assert_eq!(ValueFormat::Precision(2).parse("abc", None), None);
+        assert_eq!(ValueFormat::Precision(2).parse("NaN", None), None);
+
+        let db = ValueParser(std::sync::Arc::new(|input: &str| {
+            input.strip_suffix("dB").unwrap_or(input).trim().parse().ok()
+        }));
+        let custom = ValueFormat::Custom(std::sync::Arc::new(|value| format!("{value} dB")));
+        assert_eq!(custom.parse("-6 dB", Some(&db)), Some(-6.0));
+    }
+
+    #[test]
+    fn non_linear_scales_round_trip() {
+        let scales = [
+            SliderScale::Logarithmic,
+            SliderScale::Exponential(2.0),
+            SliderScale::Exponential(0.5),
+        ];
+        for scale in scales {
+            for i in 0..=10 {
+                let position = i as f32 / 10.0;
+                let value = scale.value_from_position(position, 20.0, 20_000.0);
+                let back = scale.position_from_value(value, 20.0, 20_000.0);
+                assert!((back - position).abs() < 1e-3, "{scale:?} at {position}: got {back}");
+            }
+        }
+
+        // Halfway along a 20Hz..20kHz log track is the geometric mean
+        let mid = SliderScale::Logarithmic.value_from_position(0.5, 20.0, 20_000.0);
+        assert!((mid - 632.456).abs() < 0.1);
+    }
+
+    #[test]
+    fn scaled_layout_maps_back_to_logical_cursor_space() {
+        let slider = slider(0.0, 10.0, SliderOrientation::Horizontal);
+        let logical_center = Vec2::new(300.0, 200.0);
+        let logical_size = Vec2::new(120.0, 20.0);
+
+        // (window scale factor, UiScale)
+        for (window_scale, ui_scale) in [(1.0, 1.0), (2.0, 1.0), (1.5, 2.0), (1.0, 0.5)] {
+            let inverse_scale_factor = 1.0 / (window_scale * ui_scale);
+            let physical_center = logical_center * window_scale;
+            let physical_size = logical_size * window_scale;
+
+            let (center, size) =
+                track_geometry(physical_size, physical_center, inverse_scale_factor, ui_scale);
+            assert!((center - logical_center).length() < 1e-3);
+            assert!((size - logical_size).length() < 1e-3);
+            sweep(&slider, center, size);
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{ron, AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+use crate::theme::{StateColors, WidgetTheme, WidgetThemePlugin};
+use crate::WidgetAppExt;
+
+/// A `WidgetTheme` loaded from a `.widgets.ron` file.
+///
+/// Every key is optional and falls back to the default theme, so a sheet only
+/// needs to list what it changes. Colors are hex strings (`"#rgb"`,
+/// `"#rrggbb"` or `"#rrggbbaa"`) and the font is an asset path:
+///
+/// ```ron
+/// (
+///     palette: (
+///         accent: "#3380e6",
+///         controls: (hover: "#bfbfbf", selected: "#e6e6e6"),
+///     ),
+///     typography: (font: "fonts/Inter.ttf", body_size: 15),
+///     radii: (control: 6),
+///     focus_ring: (color: "#ffbf00", width: 3),
+/// )
+/// ```
+///
+/// Sheets cover the theme only. The widgets' default colors come from it, but
+/// the rest of `ToggleConfig`, `ProgressBarVisuals`, `DropdownConfig` and
+/// `TabGroupConfig` (sizes, layout, transitions) is still set in code.
+///
+/// Point `ActiveWidgetStyleSheet` at a loaded sheet to apply it. With Bevy's
+/// `file_watcher` feature, saving the file restyles live widgets. A sheet that
+/// fails to load keeps the last good theme, and the asset server logs the
+/// error with its line and column.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct WidgetStyleSheet {
+    pub theme: WidgetTheme,
+}
+
+/// The style sheet that drives the global `WidgetTheme`.
+///
+/// ```ignore
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.insert_resource(ActiveWidgetStyleSheet(asset_server.load("ui/default.widgets.ron")));
+/// }
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct ActiveWidgetStyleSheet(pub Handle<WidgetStyleSheet>);
+
+#[derive(Debug)]
+pub enum WidgetStyleSheetError {
+    Io(std::io::Error),
+    Parse(ron::error::SpannedError),
+}
+
+impl fmt::Display for WidgetStyleSheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WidgetStyleSheetError::Io(error) => write!(f, "could not read style sheet: {error}"),
+            WidgetStyleSheetError::Parse(error) => write!(
+                f,
+                "invalid style sheet at line {}, column {}: {}",
+                error.position.line, error.position.col, error.code
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WidgetStyleSheetError {}
+
+impl From<std::io::Error> for WidgetStyleSheetError {
+    fn from(error: std::io::Error) -> Self {
+        WidgetStyleSheetError::Io(error)
+    }
+}
+
+// =============================================================================
+// FILE FORMAT
+// =============================================================================
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+pub(crate) struct StyleSheetFile {
+    palette: PaletteFile,
+    typography: TypographyFile,
+    radii: RadiiFile,
+    spacing: SpacingFile,
+    focus_ring: FocusRingFile,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+struct PaletteFile {
+    surface: Option<HexColor>,
+    track: Option<HexColor>,
+    accent: Option<HexColor>,
+    success: Option<HexColor>,
+    handle: Option<HexColor>,
+    border: Option<HexColor>,
+    text: Option<HexColor>,
+    control_text: Option<HexColor>,
+    error: Option<HexColor>,
+    controls: StateColorsFile,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+struct StateColorsFile {
+    normal: Option<HexColor>,
+    hover: Option<HexColor>,
+    pressed: Option<HexColor>,
+    focused: Option<HexColor>,
+    disabled: Option<HexColor>,
+    selected: Option<HexColor>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+struct TypographyFile {
+    font: Option<String>,
+    body_size: Option<f32>,
+    label_size: Option<f32>,
+    value_size: Option<f32>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+struct RadiiFile {
+    control: Option<f32>,
+    pill: Option<f32>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+struct SpacingFile {
+    gap: Option<f32>,
+    padding: Option<f32>,
+    item_padding: Option<f32>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, default)]
+struct FocusRingFile {
+    color: Option<HexColor>,
+    width: Option<f32>,
+    offset: Option<f32>,
+    radius: Option<f32>,
+}
+
+struct HexColor(Color);
+
+impl<'de> Deserialize<'de> for HexColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        Srgba::hex(&hex)
+            .map(|color| HexColor(color.into()))
+            .map_err(|error| serde::de::Error::custom(format!("invalid color \"{hex}\": {error}")))
+    }
+}
+
+fn set<T>(target: &mut T, value: Option<T>) {
+    if let Some(value) = value {
+        *target = value;
+    }
+}
+
+fn set_color(target: &mut Color, value: Option<HexColor>) {
+    set(target, value.map(|hex| hex.0));
+}
+
+impl StyleSheetFile {
+    pub(crate) fn parse(bytes: &[u8]) -> Result<Self, WidgetStyleSheetError> {
+        // Lets optional keys be written as plain values instead of `Some(..)`
+        ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_bytes(bytes)
+            .map_err(WidgetStyleSheetError::Parse)
+    }
+
+    /// Layers the sheet over the default theme. `load_font` turns the font
+    /// path into a handle.
+    pub(crate) fn into_theme(self, load_font: impl FnOnce(String) -> Handle<Font>) -> WidgetTheme {
+        let mut theme = WidgetTheme::default();
+
+        let palette = &mut theme.palette;
+        set_color(&mut palette.surface, self.palette.surface);
+        set_color(&mut palette.track, self.palette.track);
+        set_color(&mut palette.accent, self.palette.accent);
+        set_color(&mut palette.success, self.palette.success);
+        set_color(&mut palette.handle, self.palette.handle);
+        set_color(&mut palette.border, self.palette.border);
+        set_color(&mut palette.text, self.palette.text);
+        set_color(&mut palette.control_text, self.palette.control_text);
+        set_color(&mut palette.error, self.palette.error);
+        self.palette.controls.apply(&mut palette.controls);
+
+        let typography = &mut theme.typography;
+        set(&mut typography.font, self.typography.font.map(load_font));
+        set(&mut typography.body_size, self.typography.body_size);
+        set(&mut typography.label_size, self.typography.label_size);
+        set(&mut typography.value_size, self.typography.value_size);
+
+        set(&mut theme.radii.control, self.radii.control);
+        set(&mut theme.radii.pill, self.radii.pill);
+
+        set(&mut theme.spacing.gap, self.spacing.gap);
+        set(&mut theme.spacing.padding, self.spacing.padding);
+        set(&mut theme.spacing.item_padding, self.spacing.item_padding);
+
+        set_color(&mut theme.focus_ring.color, self.focus_ring.color);
+        set(&mut theme.focus_ring.width, self.focus_ring.width);
+        set(&mut theme.focus_ring.offset, self.focus_ring.offset);
+        set(&mut theme.focus_ring.radius, self.focus_ring.radius);
+
+        theme
+    }
+}
+
+impl StateColorsFile {
+    fn apply(self, colors: &mut StateColors) {
+        set_color(&mut colors.normal, self.normal);
+        set_color(&mut colors.hover, self.hover);
+        set_color(&mut colors.pressed, self.pressed);
+        set_color(&mut colors.focused, self.focused);
+        set_color(&mut colors.disabled, self.disabled);
+        set_color(&mut colors.selected, self.selected);
+    }
+}
+
+// =============================================================================
+// LOADER
+// =============================================================================
+
+#[derive(Default)]
+pub struct WidgetStyleSheetLoader;
+
+impl AssetLoader for WidgetStyleSheetLoader {
+    type Asset = WidgetStyleSheet;
+    type Settings = ();
+    type Error = WidgetStyleSheetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<WidgetStyleSheet, WidgetStyleSheetError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let theme = StyleSheetFile::parse(&bytes)?.into_theme(|path| load_context.load(path));
+        Ok(WidgetStyleSheet { theme })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["widgets.ron"]
+    }
+}
+
+// =============================================================================
+// SYSTEMS
+// =============================================================================
+
+/// Copies the active sheet into `WidgetTheme` when it is chosen, loaded or
+/// reloaded.
+pub fn apply_widget_style_sheet_system(
+    mut evr_sheets: EventReader<AssetEvent<WidgetStyleSheet>>,
+    active: Option<Res<ActiveWidgetStyleSheet>>,
+    sheets: Res<Assets<WidgetStyleSheet>>,
+    mut theme: ResMut<WidgetTheme>,
+) {
+    let events: Vec<_> = evr_sheets.read().collect();
+    let Some(active) = active else {
+        return;
+    };
+
+    let reloaded = events.iter().any(|event| {
+        event.is_loaded_with_dependencies(&active.0) || event.is_modified(&active.0)
+    });
+    if !active.is_changed() && !reloaded {
+        return;
+    }
+
+    if let Some(sheet) = sheets.get(&active.0) {
+        theme.set_if_neq(sheet.theme.clone());
+    }
+}
+
+// =============================================================================
+// PLUGIN
+// =============================================================================
+
+/// Loads `.widgets.ron` style sheets. Needs Bevy's `AssetPlugin`, so unlike
+/// the widget plugins it is not part of `WidgetsPlugin`; add it after
+/// `DefaultPlugins`.
+pub struct WidgetStyleSheetPlugin;
+
+impl Plugin for WidgetStyleSheetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin_once(WidgetThemePlugin);
+
+        app.init_asset::<WidgetStyleSheet>()
+            .init_asset_loader::<WidgetStyleSheetLoader>()
+            .add_systems(PreUpdate, apply_widget_style_sheet_system);
+    }
+}
@@ -1,12 +1,20 @@
 use bevy::prelude::*;
 use bevy::ui::FocusPolicy;
-use super::components::*;
+use bevy::ecs::component::Mutable;
+use crate::anim::AnimatedValue;
+use crate::binding::{Lens, WidgetBinding};
+use crate::focus::Focusable;
+use crate::theme::{WidgetTheme, WidgetThemeOverride};
+use super::{components::*, events::*};
 
 pub struct TabGroupBuilder<'w, 's, 'a> {
     commands: &'a mut Commands<'w, 's>,
     tabs: Vec<TabDefinition>,
     config: TabGroupConfig,
     initial_tab: usize,
+    binding: Option<WidgetBinding>,
+    theme: Option<WidgetTheme>,
+    focusable: Focusable,
 }
 
 pub struct TabDefinition {
@@ -20,23 +28,11 @@ impl<'w, 's, 'a> TabGroupBuilder<'w, 's, 'a> {
         Self {
             commands,
             tabs: Vec::new(),
-            config: TabGroupConfig {
-                tab_style: TabStyle::Top,
-                panel_style: Node::default(),
-                animation_duration: 0.2,
-                strategy: ContentStrategy::Preloaded,
-                tab_button_style: Node {
-                    padding: UiRect::all(Val::Px(8.0)),
-                    margin: UiRect::right(Val::Px(4.0)),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                active_tab_style: Node::default(),
-                inactive_tab_style: Node::default(),
-                tab_spacing: 4.0,
-            },
+            config: TabGroupConfig::default(),
             initial_tab: 0,
+            binding: None,
+            theme: None,
+            focusable: Focusable::default(),
         }
     }
 
@@ -59,6 +55,35 @@ impl<'w, 's, 'a> TabGroupBuilder<'w, 's, 'a> {
         self
     }
 
+    /// Position in Tab order; see `Focusable`. Only the selected tab is in
+    /// Tab order, the arrow keys move between tabs.
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.focusable = Focusable::with_tab_index(tab_index);
+        self
+    }
+
+    /// Styles this tab group with `theme` instead of the global `WidgetTheme`.
+    pub fn with_theme(mut self, theme: WidgetTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Keeps the selected tab and a field of resource `R` in sync both ways.
+    pub fn bind_to<R: Resource>(mut self, lens: Lens<R, usize>) -> Self {
+        self.binding = Some(WidgetBinding::resource::<TabGroup, R>(lens));
+        self
+    }
+
+    /// Keeps the selected tab and a field of component `C` on `target` in sync both ways.
+    pub fn bind_to_component<C: Component<Mutability = Mutable>>(
+        mut self,
+        target: Entity,
+        lens: Lens<C, usize>,
+    ) -> Self {
+        self.binding = Some(WidgetBinding::component::<TabGroup, C>(target, lens));
+        self
+    }
+
     pub fn spawn(self) -> Entity {
         let tab_names: Vec<String> = self.tabs.iter().map(|tab| tab.name.clone()).collect();
         let mut content_entities = Vec::new();
@@ -98,15 +123,16 @@ impl<'w, 's, 'a> TabGroupBuilder<'w, 's, 'a> {
                 tab_names: tab_names.clone(),
                 content_entities: content_entities.clone(),
                 button_entities: Vec::new(), // Will be filled below
+                tab_index: self.focusable.tab_index,
             },
             self.config.clone(),
             TabNeedsVisualUpdate,
         )).id();
 
         // Update content entities with correct group entity reference
-        for content_entity in &content_entities {
+        for (tab_index, content_entity) in content_entities.iter().enumerate() {
             self.commands.entity(*content_entity).insert(TabContent {
-                tab_index: 0, // Will be set correctly in the loop
+                tab_index,
                 group_entity,
             });
         }
@@ -119,6 +145,7 @@ impl<'w, 's, 'a> TabGroupBuilder<'w, 's, 'a> {
                 flex_direction: FlexDirection::Row,
                 ..default()
             },
+            TabBar,
         )).id();
 
         // Spawn tab buttons
@@ -128,17 +155,17 @@ impl<'w, 's, 'a> TabGroupBuilder<'w, 's, 'a> {
             let tab_button_entity = self.commands.spawn((
                 Button,
                 self.config.tab_button_style.clone(),
-                BackgroundColor(if is_active {
-                    Color::srgb(0.7, 0.7, 0.7)
-                } else {
-                    Color::srgb(0.5, 0.5, 0.5)
-                }),
                 TabButton {
                     tab_index: index,
                     group_entity,
                 },
                 TabInteractionState::default(),
+                TabButtonAnimation {
+                    scale: AnimatedValue::new(Vec3::ONE),
+                    ..default()
+                },
                 FocusPolicy::Block,
+                if is_active { self.focusable } else { Focusable::with_tab_index(-1) },
             )).id();
 
             // Add state markers
@@ -150,11 +177,7 @@ impl<'w, 's, 'a> TabGroupBuilder<'w, 's, 'a> {
 
             // Add text to button
             self.commands.entity(tab_button_entity).with_children(|parent| {
-                parent.spawn((
-                    Text::new(tab_name),
-                    TextFont { font_size: 14.0, ..default() },
-                    TextColor(Color::WHITE),
-                ));
+                parent.spawn(Text::new(tab_name));
             });
 
             button_entities.push(tab_button_entity);
@@ -166,6 +189,7 @@ impl<'w, 's, 'a> TabGroupBuilder<'w, 's, 'a> {
             tab_names: tab_names.clone(),
             content_entities: content_entities.clone(),
             button_entities: button_entities.clone(),
+            tab_index: self.focusable.tab_index,
         });
 
         // Spawn content panel
@@ -191,6 +215,21 @@ impl<'w, 's, 'a> TabGroupBuilder<'w, 's, 'a> {
         self.commands.entity(group_entity)
             .add_children(&[tab_bar_entity, panel_entity]);
 
+        if let Some(binding) = self.binding {
+            self.commands.entity(group_entity).insert(binding);
+        }
+
+        if let Some(theme) = self.theme {
+            self.commands.entity(group_entity).insert(WidgetThemeOverride(theme));
+        }
+
+        self.commands.send_event(TabChangedEvent {
+            group_entity,
+            previous_tab: self.initial_tab,
+            new_tab: self.initial_tab,
+            change_kind: TabChangeKind::Initialization,
+        });
+
         group_entity
     }
 }
\ No newline at end of file
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use crate::binding::BindableWidget;
+use super::{components::*, events::*};
+
+/// Switch tabs from code. Changes are tagged `TabChangeKind::Programmatic`;
+/// `select_tab_silently` skips the event, e.g. when restoring saved state.
+pub trait TabCommandsExt {
+    fn select_tab(&mut self, tab_index: usize) -> &mut Self;
+    fn select_tab_silently(&mut self, tab_index: usize) -> &mut Self;
+}
+
+impl TabCommandsExt for EntityCommands<'_> {
+    fn select_tab(&mut self, tab_index: usize) -> &mut Self {
+        self.queue(select_tab(tab_index, true))
+    }
+
+    fn select_tab_silently(&mut self, tab_index: usize) -> &mut Self {
+        self.queue(select_tab(tab_index, false))
+    }
+}
+
+fn select_tab(tab_index: usize, notify: bool) -> impl FnOnce(EntityWorldMut) + Send + 'static {
+    move |mut entity: EntityWorldMut| {
+        let tab_count = entity.get::<TabGroupMeta>().map_or(0, |meta| meta.tab_names.len());
+        if tab_index >= tab_count {
+            warn!("Tab index {} out of range for group {:?}", tab_index, entity.id());
+            return;
+        }
+        let Some(mut tab_group) = entity.get_mut::<TabGroup>() else {
+            return;
+        };
+        let previous_tab = tab_group.selected_tab;
+        if previous_tab == tab_index {
+            return;
+        }
+        tab_group.selected_tab = tab_index;
+        entity.insert(TabNeedsVisualUpdate);
+
+        if notify {
+            let group_entity = entity.id();
+            entity.world_scope(|world| {
+                world.send_event(TabChangedEvent {
+                    group_entity,
+                    previous_tab,
+                    new_tab: tab_index,
+                    change_kind: TabChangeKind::Programmatic,
+                });
+            });
+        }
+    }
+}
+
+impl BindableWidget for TabGroup {
+    type Value = usize;
+
+    fn bound_value(&self) -> usize {
+        self.selected_tab
+    }
+
+    fn apply_bound_value(entity: EntityWorldMut, value: usize) {
+        select_tab(value, false)(entity);
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::anim::{AnimatedValue, EasingFunction, Spring, Transition};
 
 #[derive(Component, Debug, Clone, Reflect)]
 pub struct TabGroup {
@@ -10,6 +11,8 @@ pub struct TabGroupMeta {
     pub tab_names: Vec<String>,
     pub content_entities: Vec<Entity>,
     pub button_entities: Vec<Entity>,
+    /// `Focusable::tab_index` of the selected tab's button
+    pub tab_index: i32,
 }
 
 #[derive(Component, Debug, Clone, Reflect)]
@@ -31,10 +34,10 @@ pub struct TabInactive;
 pub struct TabDisabled;
 
 #[derive(Component)]
-pub struct TabFocused;
+pub struct TabPanel;
 
 #[derive(Component)]
-pub struct TabPanel;
+pub struct TabBar;
 
 #[derive(Component)]
 pub struct TabHovered;
@@ -42,6 +45,13 @@ pub struct TabHovered;
 #[derive(Component)]
 pub struct TabPressed;
 
+/// Background color (as linear RGBA) and scale of a tab button on screen.
+#[derive(Component, Default)]
+pub struct TabButtonAnimation {
+    pub color: AnimatedValue<Vec4>,
+    pub scale: AnimatedValue<Vec3>,
+}
+
 #[derive(Component, Default)]
 pub struct TabInteractionState {
     pub was_pressed: bool,
@@ -67,6 +77,13 @@ impl Default for TabContent {
 pub struct TabGroupConfig {
     pub tab_style: TabStyle,
     pub panel_style: Node,
+    /// How tab buttons fade between state colors
+    pub color_transition: Transition,
+    /// How tab buttons shrink while pressed and grow back
+    pub scale_transition: Transition,
+    /// Seconds. When changed from 0.2, `color_transition` becomes an
+    /// ease-out tween of this length.
+    #[deprecated(note = "use `color_transition`")]
     pub animation_duration: f32,
     pub strategy: ContentStrategy,
     pub tab_button_style: Node,
@@ -76,11 +93,14 @@ pub struct TabGroupConfig {
 }
 
 impl Default for TabGroupConfig {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             tab_style: TabStyle::Top,
             panel_style: Node::default(),
-            animation_duration: 0.2,
+            color_transition: Transition::tween(DEFAULT_ANIMATION_DURATION, EasingFunction::EaseOut),
+            scale_transition: Spring::critically_damped(0.25).into(),
+            animation_duration: DEFAULT_ANIMATION_DURATION,
             strategy: ContentStrategy::Preloaded,
             tab_button_style: Node {
                 padding: UiRect::all(Val::Px(8.0)),
@@ -96,6 +116,20 @@ impl Default for TabGroupConfig {
     }
 }
 
+/// `TabGroupConfig::animation_duration` before it was replaced.
+const DEFAULT_ANIMATION_DURATION: f32 = 0.2;
+
+impl TabGroupConfig {
+    /// `color_transition`, unless a changed `animation_duration` takes over.
+    #[allow(deprecated)]
+    pub(crate) fn resolved_color_transition(&self) -> Transition {
+        if self.animation_duration != DEFAULT_ANIMATION_DURATION {
+            return Transition::tween(self.animation_duration, EasingFunction::EaseOut);
+        }
+        self.color_transition
+    }
+}
+
 #[derive(Component, Debug, Clone, Reflect, Default)]
 pub enum TabStyle {
     #[default]
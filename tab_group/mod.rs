@@ -4,8 +4,10 @@ pub mod events;
 pub mod systems;
 pub mod builder;
 pub mod resources;
+pub mod commands;
 
 pub use plugin::TabPlugin;
 pub use components::*;
 pub use events::*;
-pub use builder::*;
\ No newline at end of file
+pub use builder::*;
+pub use commands::TabCommandsExt;
\ No newline at end of file
@@ -1,10 +1,22 @@
 use bevy::prelude::*;
 use super::{events::*, systems::*};
+use crate::binding::{WidgetBindingPlugin, WidgetBindingSystem};
+use crate::focus::{WidgetFocusPlugin, WidgetFocusSystem};
+use crate::input::WidgetInputPlugin;
+use crate::motion::WidgetMotionPlugin;
+use crate::theme::WidgetThemePlugin;
+use crate::WidgetAppExt;
 
 pub struct TabPlugin;
 
 impl Plugin for TabPlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugin_once(WidgetInputPlugin);
+        app.add_plugin_once(WidgetFocusPlugin);
+        app.add_plugin_once(WidgetThemePlugin);
+        app.add_plugin_once(WidgetMotionPlugin);
+        app.add_plugin_once(WidgetBindingPlugin);
+
         app
             .add_event::<TabChangedEvent>()
             .configure_sets(
@@ -12,18 +24,28 @@ impl Plugin for TabPlugin {
                 (
                     TabSystem::ProcessInput
                         .after(bevy::input::InputSystem)
+                        .after(WidgetFocusSystem)
                         .before(bevy::ui::UiSystem::Layout),
                     TabSystem::UpdateContent.after(TabSystem::ProcessInput),
                 )
                 .chain(),
             )
+            .configure_sets(
+                Update,
+                WidgetBindingSystem.after(TabSystem::ProcessInput).before(TabSystem::UpdateContent),
+            )
             .add_systems(Update, (
                 tab_button_interaction_system.in_set(TabSystem::ProcessInput),
                 tab_keyboard_navigation_system.in_set(TabSystem::ProcessInput),
-                tab_focus_system.in_set(TabSystem::ProcessInput),
                 tab_content_management_system.in_set(TabSystem::UpdateContent),
+                tab_visual_update_system.in_set(TabSystem::UpdateContent),
+                tab_focus_order_system.in_set(TabSystem::UpdateContent),
                 tab_content_visibility_system.after(TabSystem::UpdateContent), // Responds to events
             ))
-            .add_systems(Update, tab_continuous_visual_update_system); // Independent continuous system
+            .add_systems(Update, (
+                tab_theme_system,
+                tab_continuous_visual_update_system, // Independent continuous system
+            ).chain())
+            .add_systems(Update, tab_accessibility_system.after(TabSystem::UpdateContent));
     }
 }
\ No newline at end of file
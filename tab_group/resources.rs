@@ -1,3 +1 @@
-use bevy::prelude::*;
-
-// Resources for tab group functionality
\ No newline at end of file
+// Resources for tab group functionality
@@ -1,5 +1,11 @@
+use accesskit::Role;
+use bevy::a11y::AccessibilityNode;
 use bevy::prelude::*;
 use super::{components::*, events::*};
+use crate::accessibility::{needs_accessibility_sync, node_id, set_disabled, sync_accessibility_node};
+use crate::focus::{Focusable, WidgetFocus, WidgetFocused};
+use crate::motion::WidgetMotionSettings;
+use crate::theme::{ThemeLookup, WidgetVisualState};
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum TabSystem {
@@ -15,14 +21,9 @@ pub fn tab_button_interaction_system(
     mut evw_tab_change: EventWriter<TabChangedEvent>,
 ) {
     for (button_entity, interaction, tab_button) in q_tab_buttons.iter() {
-        #[cfg(debug_assertions)]
-        info!("Tab button {:?} interaction: {:?}, tab_index: {}, group_entity: {:?}", 
-              button_entity, interaction, tab_button.tab_index, tab_button.group_entity);
         
         match interaction {
             Interaction::Pressed => {
-                #[cfg(debug_assertions)]
-                info!("Tab button pressed: tab_index {}", tab_button.tab_index);
                 
                 commands.entity(button_entity).insert(TabPressed);
                 
@@ -30,8 +31,6 @@ pub fn tab_button_interaction_system(
                 if let Ok((mut tab_group, _tab_meta)) = q_tab_groups.get_mut(tab_button.group_entity) {
                     let previous_tab = tab_group.selected_tab;
                     if previous_tab != tab_button.tab_index {
-                        #[cfg(debug_assertions)]
-                        info!("Switching tab from {} to {}", previous_tab, tab_button.tab_index);
                         
                         tab_group.selected_tab = tab_button.tab_index;
                         
@@ -43,16 +42,7 @@ pub fn tab_button_interaction_system(
                         });
                         
                         commands.entity(tab_button.group_entity).insert(TabNeedsVisualUpdate);
-                        
-                        #[cfg(debug_assertions)]
-                        info!("Tab switch complete, marked group for visual update");
-                    } else {
-                        #[cfg(debug_assertions)]
-                        info!("Tab {} already selected, no change needed", tab_button.tab_index);
                     }
-                } else {
-                    #[cfg(debug_assertions)]
-                    warn!("Could not find tab group {:?} for button", tab_button.group_entity);
                 }
             }
             Interaction::Hovered => {
@@ -70,11 +60,12 @@ pub fn tab_button_interaction_system(
 pub fn tab_keyboard_navigation_system(
     mut commands: Commands,
     mut q_tab_groups: Query<(Entity, &mut TabGroup, &TabGroupMeta)>,
-    q_focused_tabs: Query<(&TabButton, &TabFocused)>,
+    q_focused_tabs: Query<&TabButton, With<WidgetFocused>>,
     keys: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<WidgetFocus>,
     mut evw_tab_change: EventWriter<TabChangedEvent>,
 ) {
-    for (focused_button, _) in &q_focused_tabs {
+    for focused_button in &q_focused_tabs {
         if let Ok((group_entity, mut tab_group, tab_meta)) = q_tab_groups.get_mut(focused_button.group_entity) {
             let mut new_tab = tab_group.selected_tab;
             
@@ -93,6 +84,11 @@ pub fn tab_keyboard_navigation_system(
                 tab_group.selected_tab = new_tab;
                 
                 commands.entity(group_entity).insert(TabNeedsVisualUpdate);
+
+                // Focus follows the selection, so arrows keep working from the new tab
+                if let Some(&button_entity) = tab_meta.button_entities.get(new_tab) {
+                    focus.set(button_entity);
+                }
                 
                 evw_tab_change.write(TabChangedEvent {
                     group_entity,
@@ -105,17 +101,27 @@ pub fn tab_keyboard_navigation_system(
     }
 }
 
+/// Keeps only the selected tab's button in Tab order.
+pub fn tab_focus_order_system(
+    q_tab_groups: Query<(&TabGroup, &TabGroupMeta), Changed<TabGroup>>,
+    mut q_tab_buttons: Query<&mut Focusable, With<TabButton>>,
+) {
+    for (tab_group, tab_meta) in &q_tab_groups {
+        for (index, &button_entity) in tab_meta.button_entities.iter().enumerate() {
+            if let Ok(mut focusable) = q_tab_buttons.get_mut(button_entity) {
+                let tab_index = if index == tab_group.selected_tab { tab_meta.tab_index } else { -1 };
+                focusable.set_if_neq(Focusable::with_tab_index(tab_index));
+            }
+        }
+    }
+}
+
 pub fn tab_visual_update_system(
     mut commands: Commands,
     q_tab_groups: Query<(Entity, &TabGroup, &TabGroupMeta), With<TabNeedsVisualUpdate>>,
     mut q_tab_content: Query<(&TabContent, &mut Visibility)>,
 ) {
     for (group_entity, tab_group, tab_meta) in q_tab_groups.iter() {
-        #[cfg(debug_assertions)]
-        info!("Processing visual update for tab group {:?}, selected_tab: {}", 
-              group_entity, tab_group.selected_tab);
-        
-        let mut content_updated = 0;
         
         // Update content visibility only
         for (tab_content, mut visibility) in q_tab_content.iter_mut() {
@@ -126,19 +132,18 @@ pub fn tab_visual_update_system(
                     Visibility::Hidden
                 };
                 
-                #[cfg(debug_assertions)]
-                if *visibility != new_visibility {
-                    info!("Updating content visibility: tab_index {} -> {:?}", 
-                          tab_content.tab_index, new_visibility);
-                }
-                
                 *visibility = new_visibility;
-                content_updated += 1;
             }
         }
         
-        #[cfg(debug_assertions)]
-        info!("Updated visibility for {} content entities", content_updated);
+        
+        for (tab_index, button_entity) in tab_meta.button_entities.iter().enumerate() {
+            if tab_index == tab_group.selected_tab {
+                commands.entity(*button_entity).insert(TabActive).remove::<TabInactive>();
+            } else {
+                commands.entity(*button_entity).insert(TabInactive).remove::<TabActive>();
+            }
+        }
         
         commands.entity(group_entity).remove::<TabNeedsVisualUpdate>();
     }
@@ -148,7 +153,6 @@ pub fn tab_content_management_system(
     mut commands: Commands,
     mut q_tab_groups: Query<(&TabGroup, &TabGroupMeta, &TabGroupConfig)>,
     q_tab_content: Query<&TabContent>,
-    asset_server: Res<AssetServer>,
 ) {
     for (tab_group, tab_meta, config) in &mut q_tab_groups {
         match config.strategy {
@@ -172,82 +176,94 @@ pub fn tab_content_management_system(
     }
 }
 
-pub fn tab_focus_system(
-    mut commands: Commands,
-    q_tab_buttons: Query<(Entity, &TabButton), With<TabButton>>,
-    keyboard: Res<ButtonInput<KeyCode>>,
-    mut q_focused_tabs: Query<Entity, With<TabFocused>>,
-    q_tab_groups: Query<(&TabGroup, &TabGroupMeta)>,
-    mut q_tab_buttons_with_focus: Query<(Entity, &TabButton), With<TabFocused>>,
-) {
-    // Keyboard-based focus navigation
-    if keyboard.just_pressed(KeyCode::ArrowLeft) || keyboard.just_pressed(KeyCode::ArrowRight) {
-        for (focused_entity, focused_tab_button) in q_tab_buttons_with_focus.iter() {
-            if let Ok((tab_group, tab_meta)) = q_tab_groups.get(focused_tab_button.group_entity) {
-                let current_index = focused_tab_button.tab_index;
-                let tab_count = tab_meta.tab_names.len();
-                
-                let new_index = if keyboard.just_pressed(KeyCode::ArrowLeft) {
-                    if current_index == 0 { tab_count - 1 } else { current_index - 1 }
-                } else {
-                    (current_index + 1) % tab_count
-                };
-                
-                // Find and focus the new tab
-                for (entity, tab_button) in q_tab_buttons.iter() {
-                    if tab_button.group_entity == focused_tab_button.group_entity 
-                        && tab_button.tab_index == new_index {
-                        commands.entity(entity).insert(TabFocused);
-                        commands.entity(focused_entity).remove::<TabFocused>();
-                        break;
-                    }
-                }
-            }
-        }
-    }
-    
-    // Mouse-based focus would need a separate system with proper Interaction query
-}
-
 pub fn tab_continuous_visual_update_system(
+    themes: ThemeLookup,
     mut q_tab_buttons: Query<(
+        Entity,
         &TabButton, 
-        Option<&TabActive>, 
         Option<&TabHovered>, 
         Option<&TabPressed>, 
-        Option<&TabFocused>, // Added focus
+        Has<WidgetFocused>,
+        Option<&TabDisabled>,
+        &mut TabButtonAnimation,
         &mut BackgroundColor, 
         &mut Transform
     )>,
-    q_tab_groups: Query<&TabGroup>,
+    q_tab_groups: Query<(&TabGroup, &TabGroupConfig)>,
     time: Res<Time>,
+    motion: Res<WidgetMotionSettings>,
 ) {
-    for (tab_button, is_active, is_hovered, is_pressed, is_focused, mut bg_color, mut transform) in &mut q_tab_buttons {
-        if let Ok(tab_group) = q_tab_groups.get(tab_button.group_entity) {
-            let is_selected = tab_button.tab_index == tab_group.selected_tab;
-            
-            // Determine target visual state with clear priority
-            let target_color = if is_selected {
-                Color::srgb(0.9, 0.9, 0.9) // Active tab (light, highest priority)
-            } else if is_pressed.is_some() {
-                Color::srgb(0.6, 0.6, 0.6) // Pressed (darker)
-            } else if is_focused.is_some() {
-                Color::srgb(0.8, 0.8, 0.9) // Keyboard focused (slight blue tint)
-            } else if is_hovered.is_some() {
-                Color::srgb(0.75, 0.75, 0.75) // Hovered (light gray)
-            } else {
-                Color::srgb(0.65, 0.65, 0.65) // Inactive (medium gray)
-            };
+    for (entity, tab_button, is_hovered, is_pressed, is_focused, is_disabled, mut animation, mut bg_color, mut transform) in &mut q_tab_buttons {
+        if let Ok((tab_group, config)) = q_tab_groups.get(tab_button.group_entity) {
+            // Target visual state, prioritised by the theme's state colors
+            let target_color = themes.get(entity).palette.controls.pick(WidgetVisualState {
+                selected: tab_button.tab_index == tab_group.selected_tab,
+                pressed: is_pressed.is_some(),
+                focused: is_focused,
+                hovered: is_hovered.is_some(),
+                disabled: is_disabled.is_some(),
+            });
             
             let target_scale = if is_pressed.is_some() { 0.95 } else { 1.0 };
             
-            // Smooth interpolation
-            bg_color.0 = bg_color.0.mix(&target_color, time.delta_secs() * 10.0);
-            transform.scale = transform.scale.lerp(Vec3::splat(target_scale), time.delta_secs() * 15.0);
+            animation.color.set_target(LinearRgba::from(target_color).to_vec4());
+            if !animation.color.is_settled() {
+                let finished = animation.color.update(&config.resolved_color_transition(), &motion, &time);
+                // Land on the exact theme color rather than its linear round trip
+                bg_color.0 = if finished {
+                    target_color
+                } else {
+                    LinearRgba::from_vec4(animation.color.value()).into()
+                };
+            }
+            
+            animation.scale.set_target(Vec3::splat(target_scale));
+            if !animation.scale.is_settled() {
+                animation.scale.update(&config.scale_transition, &motion, &time);
+                transform.scale = animation.scale.value();
+            }
         }
     }
 }
 
+/// Applies theme radii and label fonts to new tab buttons, and to every tab
+/// button when the theme changes. New buttons also start at their state
+/// color instead of fading in.
+pub fn tab_theme_system(
+    themes: ThemeLookup,
+    mut q_tab_buttons: Query<(Entity, Ref<TabButton>, &mut BorderRadius, &mut BackgroundColor, Option<&mut TabButtonAnimation>)>,
+    mut q_labels: Query<(Entity, &ChildOf, Ref<Text>, &mut TextColor, &mut TextFont)>,
+    q_tab_groups: Query<&TabGroup>,
+) {
+    let restyle_all = themes.changed();
+
+    for (entity, tab_button, mut radius, mut bg_color, animation) in &mut q_tab_buttons {
+        if !restyle_all && !tab_button.is_added() {
+            continue;
+        }
+        let theme = themes.get(entity);
+        *radius = BorderRadius::all(Val::Px(theme.radii.control));
+        if tab_button.is_added() {
+            let selected = q_tab_groups
+                .get(tab_button.group_entity)
+                .is_ok_and(|tab_group| tab_group.selected_tab == tab_button.tab_index);
+            bg_color.0 = theme.palette.controls.pick(WidgetVisualState { selected, ..default() });
+            if let Some(mut animation) = animation {
+                animation.color.jump_to(LinearRgba::from(bg_color.0).to_vec4());
+            }
+        }
+    }
+
+    for (entity, parent, text, mut color, mut font) in &mut q_labels {
+        if !q_tab_buttons.contains(parent.parent()) || (!restyle_all && !text.is_added()) {
+            continue;
+        }
+        let theme = themes.get(entity);
+        color.0 = theme.palette.control_text;
+        *font = theme.typography.text_font(theme.typography.body_size);
+    }
+}
+
 // systems.rs (new event-driven content system)
 pub fn tab_content_visibility_system(
     mut commands: Commands,
@@ -256,11 +272,6 @@ pub fn tab_content_visibility_system(
     q_tab_buttons: Query<(Entity, &TabButton)>,
 ) {
     for event in evr_tab_change.read() {
-        #[cfg(debug_assertions)]
-        info!("Received TabChangedEvent: group={:?}, from {} to {}", 
-              event.group_entity, event.previous_tab, event.new_tab);
-        
-        let mut content_updated = 0;
         
         // Hide all content in this group
         for (tab_content, mut visibility) in q_tab_content.iter_mut() {
@@ -271,14 +282,7 @@ pub fn tab_content_visibility_system(
                     Visibility::Hidden
                 };
                 
-                #[cfg(debug_assertions)]
-                if *visibility != new_visibility {
-                    info!("Content visibility change: tab_index {} {:?} -> {:?}", 
-                          tab_content.tab_index, *visibility, new_visibility);
-                }
-                
                 *visibility = new_visibility;
-                content_updated += 1;
             }
         }
         
@@ -288,18 +292,60 @@ pub fn tab_content_visibility_system(
                 if tab_button.tab_index == event.new_tab {
                     // This button should be active
                     commands.entity(button_entity).insert(TabActive).remove::<TabInactive>();
-                    #[cfg(debug_assertions)]
-                    info!("Set button {} (tab {}) to active", button_entity, tab_button.tab_index);
                 } else {
                     // This button should be inactive
                     commands.entity(button_entity).insert(TabInactive).remove::<TabActive>();
-                    #[cfg(debug_assertions)]
-                    info!("Set button {} (tab {}) to inactive", button_entity, tab_button.tab_index);
                 }
             }
         }
         
-        #[cfg(debug_assertions)]
-        info!("Updated visibility for {} content entities", content_updated);
     }
-}
\ No newline at end of file
+}
+
+/// Publishes the tab bar as a tablist, its buttons as tabs with the selected
+/// state and the panel they control, and each content entity as a tabpanel.
+pub fn tab_accessibility_system(
+    mut commands: Commands,
+    q_tab_groups: Query<(Ref<TabGroup>, Ref<TabGroupMeta>)>,
+    mut q_bars: Query<(Entity, Option<&mut AccessibilityNode>), (With<TabBar>, Without<TabButton>, Without<TabContent>)>,
+    mut q_tab_buttons: Query<
+        (Entity, &TabButton, Has<TabDisabled>, Option<&mut AccessibilityNode>),
+        Without<TabContent>
+    >,
+    mut q_contents: Query<(Entity, Option<&mut AccessibilityNode>), With<TabContent>>,
+) {
+    let group_changed = |group_entity: Entity| {
+        q_tab_groups
+            .get(group_entity)
+            .is_ok_and(|(tab_group, meta)| tab_group.is_changed() || meta.is_changed())
+    };
+
+    for (entity, node) in &mut q_bars {
+        if needs_accessibility_sync(false, &node) {
+            sync_accessibility_node(&mut commands, entity, node, Role::TabList, |_| {});
+        }
+    }
+
+    for (entity, tab_button, disabled, node) in &mut q_tab_buttons {
+        if !needs_accessibility_sync(group_changed(tab_button.group_entity), &node) {
+            continue;
+        }
+        let group = q_tab_groups.get(tab_button.group_entity).ok();
+        let selected = group.as_ref().is_some_and(|(tab_group, _)| tab_group.selected_tab == tab_button.tab_index);
+        let panel = group.and_then(|(_, meta)| meta.content_entities.get(tab_button.tab_index).copied());
+        sync_accessibility_node(&mut commands, entity, node, Role::Tab, |node| {
+            node.set_selected(selected);
+            set_disabled(node, disabled);
+            match panel {
+                Some(panel) => node.set_controls(vec![node_id(panel)]),
+                None => node.clear_controls(),
+            }
+        });
+    }
+
+    for (entity, node) in &mut q_contents {
+        if needs_accessibility_sync(false, &node) {
+            sync_accessibility_node(&mut commands, entity, node, Role::TabPanel, |_| {});
+        }
+    }
+}
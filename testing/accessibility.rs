@@ -0,0 +1,147 @@
+use accesskit::{NodeId, Role, Toggled};
+use bevy::a11y::AccessibilityNode;
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::dropdown::*;
+use crate::progress_bar::*;
+use crate::radio_group::*;
+use crate::slider::*;
+use crate::tab_group::*;
+use crate::toggle::*;
+
+fn node(app: &mut WidgetTestApp, entity: Entity) -> &AccessibilityNode {
+    app.get::<AccessibilityNode>(entity)
+}
+
+#[test]
+fn toggle_is_a_switch_that_tracks_its_state() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+    app.update();
+    assert_eq!(node(&mut app, toggle).role(), Role::Switch);
+    assert_eq!(node(&mut app, toggle).toggled(), Some(Toggled::False));
+
+    app.click(toggle);
+
+    assert_eq!(node(&mut app, toggle).toggled(), Some(Toggled::True));
+}
+
+#[test]
+fn re_enabling_a_toggle_clears_its_disabled_flag() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).disabled(true).spawn());
+    app.update();
+    assert!(node(&mut app, toggle).is_disabled());
+
+    app.run_commands(|commands| {
+        commands.entity(toggle).remove::<ToggleDisabled>();
+    });
+    app.update();
+
+    assert!(!node(&mut app, toggle).is_disabled());
+}
+
+#[test]
+fn re_enabling_a_radio_group_clears_its_options_disabled_flag() {
+    let mut app = WidgetTestApp::new();
+    let group = app.spawn_with(|commands| {
+        RadioGroupBuilder::new(commands).with_option("A").with_option("B").disabled(true).spawn()
+    });
+    app.update();
+    let option = app.entities::<With<RadioOption>>()[0];
+    assert!(node(&mut app, option).is_disabled());
+
+    app.run_commands(|commands| {
+        commands.entity(group).remove::<RadioDisabled>();
+    });
+    app.update();
+
+    assert!(!node(&mut app, group).is_disabled());
+    assert!(!node(&mut app, option).is_disabled());
+}
+
+#[test]
+fn slider_exposes_range_step_and_formatted_value() {
+    let mut app = WidgetTestApp::new();
+    let slider = app.spawn_with(|commands| {
+        SliderBuilder::new(commands).with_range(0.0, 10.0).with_value(2.5).with_step(0.5).spawn()
+    });
+    app.update();
+
+    let node = node(&mut app, slider);
+    assert_eq!(node.role(), Role::Slider);
+    assert_eq!(node.numeric_value(), Some(2.5));
+    assert_eq!(node.min_numeric_value(), Some(0.0));
+    assert_eq!(node.max_numeric_value(), Some(10.0));
+    assert_eq!(node.numeric_value_step(), Some(0.5));
+    assert_eq!(node.value(), Some("2.50"));
+}
+
+#[test]
+fn dropdown_is_a_combobox_with_a_listbox_of_options() {
+    let mut app = WidgetTestApp::new();
+    let dropdown = app.spawn_dropdown(
+        DropdownBuilder::new()
+            .with_option("Red", None)
+            .with_option("Green", None)
+            .build(),
+    );
+    app.update();
+    assert_eq!(node(&mut app, dropdown).role(), Role::ComboBox);
+    assert_eq!(node(&mut app, dropdown).is_expanded(), Some(false));
+    let list = app.entities::<With<DropdownList>>()[0];
+    assert_eq!(node(&mut app, list).role(), Role::ListBox);
+
+    let options = app.entities::<With<DropdownOptionElement>>();
+    app.click(options[1]);
+
+    assert_eq!(node(&mut app, dropdown).value(), Some("Green"));
+    assert_eq!(node(&mut app, options[0]).role(), Role::ListBoxOption);
+    assert_eq!(node(&mut app, options[0]).is_selected(), Some(false));
+    assert_eq!(node(&mut app, options[1]).is_selected(), Some(true));
+    assert_eq!(node(&mut app, options[1]).label(), Some("Green"));
+}
+
+#[test]
+fn tab_group_is_a_tablist_of_tabs_and_panels() {
+    let mut app = WidgetTestApp::new();
+    let group = app.spawn_with(|commands| {
+        TabGroupBuilder::new(commands)
+            .with_tab("One", |commands| commands.spawn(Node::default()).id())
+            .with_tab("Two", |commands| commands.spawn(Node::default()).id())
+            .spawn()
+    });
+    app.update();
+    let meta = app.get::<TabGroupMeta>(group).clone();
+    let bar = app.entities::<With<TabBar>>()[0];
+    assert_eq!(node(&mut app, bar).role(), Role::TabList);
+    assert_eq!(node(&mut app, meta.content_entities[1]).role(), Role::TabPanel);
+    assert_eq!(node(&mut app, meta.button_entities[0]).role(), Role::Tab);
+    assert_eq!(node(&mut app, meta.button_entities[0]).is_selected(), Some(true));
+    assert_eq!(
+        node(&mut app, meta.button_entities[1]).controls(),
+        [NodeId(meta.content_entities[1].to_bits())]
+    );
+
+    app.click(meta.button_entities[1]);
+
+    assert_eq!(node(&mut app, meta.button_entities[0]).is_selected(), Some(false));
+    assert_eq!(node(&mut app, meta.button_entities[1]).is_selected(), Some(true));
+}
+
+#[test]
+fn progress_bar_reports_its_value() {
+    let mut app = WidgetTestApp::new();
+    let bar = app.spawn_with(|commands| ProgressBarBuilder::new(commands).with_value(0.0, 200.0).spawn());
+    app.update();
+
+    app.get_mut::<ProgressBar>(bar).set_value(50.0);
+    app.update();
+
+    let node = node(&mut app, bar);
+    assert_eq!(node.role(), Role::ProgressIndicator);
+    assert_eq!(node.numeric_value(), Some(50.0));
+    assert_eq!(node.max_numeric_value(), Some(200.0));
+    assert_eq!(node.value(), Some("25%"));
+}
@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::anim::*;
+use crate::dropdown::*;
+use crate::progress_bar::ProgressBarVisuals;
+use crate::tab_group::TabGroupConfig;
+use crate::toggle::ToggleConfig;
+
+/// Steps a value from 0 towards 1 at `fps` and returns it after `secs`.
+fn value_after(transition: Transition, fps: f32, secs: f32) -> f32 {
+    let mut value = AnimatedValue::<f32>::new(0.0);
+    value.set_target(1.0);
+    for _ in 0..(secs * fps).round() as usize {
+        value.step(&transition, 1.0 / fps);
+    }
+    value.value()
+}
+
+#[test]
+fn cubic_bezier_matches_the_css_curves() {
+    let linear = EasingFunction::CubicBezier(0.0, 0.0, 1.0, 1.0);
+    let ease = EasingFunction::CubicBezier(0.25, 0.1, 0.25, 1.0);
+
+    for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        assert!((linear.sample(t) - t).abs() < 1e-4);
+    }
+    assert_eq!(ease.sample(0.0), 0.0);
+    assert!((ease.sample(1.0) - 1.0).abs() < 1e-4);
+    // CSS `ease` at the halfway point
+    assert!((ease.sample(0.5) - 0.8024).abs() < 1e-3);
+}
+
+#[test]
+fn tweens_and_springs_do_not_depend_on_the_frame_rate() {
+    let tween: Transition = Tween::new(0.5, EasingFunction::EaseInOut).into();
+    let spring: Transition = Spring::default().into();
+
+    for transition in [tween, spring] {
+        let slow = value_after(transition, 30.0, 0.2);
+        let fast = value_after(transition, 144.0, 0.2);
+        assert!((slow - fast).abs() < 0.02, "{transition:?}: {slow} vs {fast}");
+    }
+}
+
+#[test]
+fn tween_reports_the_frame_it_finishes() {
+    let tween: Transition = Tween::new(0.1, EasingFunction::Linear).into();
+    let mut value = AnimatedValue::<f32>::new(0.0);
+    value.set_target(2.0);
+
+    assert!(!value.step(&tween, 0.05));
+    assert_eq!(value.value(), 1.0);
+    assert!(value.step(&tween, 0.05));
+    assert_eq!(value.value(), 2.0);
+    assert!(value.is_settled());
+    assert!(!value.step(&tween, 0.05));
+}
+
+#[test]
+fn critically_damped_spring_settles_without_overshoot() {
+    let spring: Transition = Spring::critically_damped(0.3).into();
+    let mut value = AnimatedValue::<f32>::new(0.0);
+    value.set_target(1.0);
+
+    let mut peak: f32 = 0.0;
+    for frame in 1..=120 {
+        value.step(&spring, 1.0 / 60.0);
+        peak = peak.max(value.value());
+        if frame == 18 {
+            assert!(1.0 - value.value() < 0.015, "{} after 0.3s", value.value());
+        }
+    }
+
+    assert!(value.is_settled());
+    assert!(peak <= 1.0 + 1e-3);
+}
+
+#[test]
+fn spring_keeps_its_velocity_when_retargeted() {
+    let spring: Transition = Spring::default().into();
+    let mut value = AnimatedValue::<f32>::new(0.0);
+    value.set_target(1.0);
+    value.step(&spring, 0.05);
+    let moving = value.value();
+
+    value.set_target(0.0);
+    value.step(&spring, 1.0 / 60.0);
+
+    // Still carried forward by its momentum
+    assert!(value.value() > moving);
+}
+
+#[test]
+fn widgets_can_choose_their_transition() {
+    let mut app = WidgetTestApp::new();
+    let config = DropdownConfig {
+        transition: Tween::new(0.1, EasingFunction::Linear).into(),
+        ..default()
+    };
+    let dropdown = app.spawn_dropdown(DropdownBuilder::new().with_option("Red", None).with_config(config).build());
+    let button = app.entities::<With<DropdownButton>>()[0];
+
+    app.click(button);
+    let opening = app.get::<DropdownAnimation>(dropdown).progress.value();
+    app.step(6);
+
+    assert!(opening > 0.0 && opening < 1.0);
+    assert_eq!(app.get::<DropdownAnimation>(dropdown).progress.value(), 1.0);
+}
+
+#[test]
+#[allow(deprecated)]
+fn legacy_durations_become_tweens() {
+    let toggle = ToggleConfig {
+        animation_duration: 0.5,
+        ..default()
+    };
+    let tabs = TabGroupConfig {
+        animation_duration: 0.5,
+        ..default()
+    };
+    let progress = ProgressBarVisuals {
+        easing: EasingFunction::Linear,
+        ..default()
+    };
+    let eased = Transition::tween(0.5, EasingFunction::EaseOut);
+
+    assert_eq!(toggle.transitions(), (eased, eased));
+    assert_eq!(tabs.resolved_color_transition(), eased);
+    assert_eq!(progress.resolved_transition(), Transition::tween(0.3, EasingFunction::Linear));
+    assert_eq!(ToggleConfig::default().transitions(), (Transition::default(), Transition::default()));
+}
+
+#[test]
+#[allow(deprecated)]
+fn legacy_dropdown_animation_config_is_a_spring() {
+    let transition = Transition::from(AnimationConfig {
+        stiffness: 300.0,
+        damping: 20.0,
+        precision: 0.01,
+    });
+
+    assert_eq!(transition, Spring::new(300.0, 20.0).into());
+}
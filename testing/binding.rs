@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::binding::Lens;
+use crate::dropdown::*;
+use crate::slider::{components::Slider, SliderBuilder, SliderValueChangedEvent};
+use crate::toggle::*;
+
+#[derive(Resource, Default)]
+struct Settings {
+    muted: bool,
+    quality: Option<usize>,
+}
+
+#[derive(Component)]
+struct Volume(f32);
+
+fn muted_lens() -> Lens<Settings, bool> {
+    Lens::<Settings, _>::new(|settings| settings.muted, |settings, muted| settings.muted = muted)
+}
+
+#[test]
+fn bound_data_sets_the_initial_state() {
+    let mut app = WidgetTestApp::new();
+    app.world().insert_resource(Settings { muted: true, ..default() });
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).bind_to(muted_lens()).spawn());
+
+    app.update();
+
+    assert!(app.get::<Toggle>(toggle).is_on());
+    assert!(app.take_events::<ToggleChangedEvent>().is_empty());
+}
+
+#[test]
+fn user_changes_are_written_back() {
+    let mut app = WidgetTestApp::new();
+    app.world().init_resource::<Settings>();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).bind_to(muted_lens()).spawn());
+    app.update();
+
+    app.click(toggle);
+
+    assert!(app.world().resource::<Settings>().muted);
+}
+
+#[test]
+fn external_changes_follow_into_the_widget_without_events() {
+    let mut app = WidgetTestApp::new();
+    app.world().init_resource::<Settings>();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).bind_to(muted_lens()).spawn());
+    app.update();
+
+    app.world().resource_mut::<Settings>().muted = true;
+    app.update();
+    assert!(app.get::<Toggle>(toggle).is_on());
+
+    app.step(5);
+    assert!(app.get::<Toggle>(toggle).is_on());
+    assert!(app.world().resource::<Settings>().muted);
+    assert!(app.take_events::<ToggleChangedEvent>().is_empty());
+}
+
+#[test]
+fn slider_follows_a_component_field() {
+    let mut app = WidgetTestApp::new();
+    let target = app.world().spawn(Volume(30.0)).id();
+    let slider = app.spawn_with(|commands| {
+        SliderBuilder::new(commands)
+            .with_range(0.0, 100.0)
+            .bind_to_component(
+                target,
+                Lens::<Volume, _>::new(|volume| volume.0, |volume, value| volume.0 = value),
+            )
+            .spawn()
+    });
+    app.update();
+    assert_eq!(app.get::<Slider>(slider).value, 30.0);
+
+    app.get_mut::<Volume>(target).0 = 80.0;
+    app.update();
+    assert_eq!(app.get::<Slider>(slider).value, 80.0);
+    assert!(app.take_events::<SliderValueChangedEvent>().is_empty());
+}
+
+#[test]
+fn dropdown_binds_to_the_option_index() {
+    let mut app = WidgetTestApp::new();
+    app.world().insert_resource(Settings { quality: Some(1), ..default() });
+    let dropdown = app.spawn_dropdown(
+        DropdownBuilder::new()
+            .with_option("Low", None)
+            .with_option("High", None)
+            .bind_to(Lens::<Settings, _>::new(
+                |settings| settings.quality,
+                |settings, quality| settings.quality = quality,
+            ))
+            .build(),
+    );
+    app.update();
+
+    let ids = app.get::<Dropdown>(dropdown).option_ids.clone();
+    assert_eq!(app.get::<Dropdown>(dropdown).selected_id, Some(ids[1]));
+
+    app.run_commands(|commands| {
+        commands.entity(dropdown).select_dropdown_option(Some(ids[0]));
+    });
+    app.update();
+    assert_eq!(app.world().resource::<Settings>().quality, Some(0));
+}
@@ -0,0 +1,91 @@
+use accesskit::{Role, Toggled};
+use bevy::a11y::AccessibilityNode;
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::toggle::*;
+
+fn glyph_display(app: &mut WidgetTestApp, checkbox: Entity) -> (Display, Display) {
+    let parts = app.get::<CheckboxParts>(checkbox).clone();
+    (app.get::<Node>(parts.checkmark).display, app.get::<Node>(parts.dash).display)
+}
+
+#[test]
+fn click_checks_the_box_and_shows_the_checkmark() {
+    let mut app = WidgetTestApp::new();
+    let checkbox = app.spawn_with(|commands| CheckboxBuilder::new(commands).spawn());
+    app.update();
+    assert_eq!(glyph_display(&mut app, checkbox), (Display::None, Display::None));
+
+    app.click(checkbox);
+
+    assert_eq!(app.get::<Toggle>(checkbox).state, ToggleState::On);
+    assert_eq!(glyph_display(&mut app, checkbox), (Display::Flex, Display::None));
+    let config = app.get::<ToggleConfig>(checkbox).clone();
+    assert_eq!(app.get::<BackgroundColor>(checkbox).0, config.on_color);
+}
+
+#[test]
+fn tri_state_cycle_passes_through_mixed() {
+    let mut app = WidgetTestApp::new();
+    let checkbox = app.spawn_with(|commands| {
+        CheckboxBuilder::new(commands).with_cycle(ToggleCycle::TriState).spawn()
+    });
+
+    let mut states = Vec::new();
+    for _ in 0..3 {
+        app.click(checkbox);
+        states.push(app.get::<Toggle>(checkbox).state);
+    }
+
+    assert_eq!(states, [ToggleState::On, ToggleState::Mixed, ToggleState::Off]);
+}
+
+#[test]
+fn mixed_shows_the_dash_and_a_click_turns_it_on() {
+    let mut app = WidgetTestApp::new();
+    let checkbox = app.spawn_with(|commands| CheckboxBuilder::new(commands).spawn());
+
+    app.run_commands(|commands| {
+        commands.entity(checkbox).set_toggle(ToggleState::Mixed);
+    });
+    app.update();
+    assert_eq!(glyph_display(&mut app, checkbox), (Display::None, Display::Flex));
+    assert!(!app.get::<Toggle>(checkbox).is_on());
+
+    app.click(checkbox);
+
+    let events = app.take_events::<ToggleChangedEvent>();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].previous_state, ToggleState::Mixed);
+    assert_eq!(events[1].new_state, ToggleState::On);
+    assert_eq!(events[1].kind, ToggleChangeKind::User);
+}
+
+#[test]
+fn checkbox_is_announced_with_its_mixed_state() {
+    let mut app = WidgetTestApp::new();
+    let checkbox = app.spawn_with(|commands| {
+        CheckboxBuilder::new(commands).with_initial_state(ToggleState::Mixed).spawn()
+    });
+    app.update();
+
+    let node = app.get::<AccessibilityNode>(checkbox);
+    assert_eq!(node.role(), Role::CheckBox);
+    assert_eq!(node.toggled(), Some(Toggled::Mixed));
+}
+
+#[test]
+fn checkboxes_do_not_drag() {
+    let mut app = WidgetTestApp::new();
+    let checkbox = app.spawn_with(|commands| CheckboxBuilder::new(commands).spawn());
+    app.update();
+
+    app.move_cursor(Vec2::new(100.0, 10.0))
+        .set_interaction(checkbox, Interaction::Pressed)
+        .press_mouse(MouseButton::Left)
+        .update();
+    app.move_cursor(Vec2::new(130.0, 10.0)).update();
+
+    assert!(!app.get::<ToggleDragState>(checkbox).is_dragging);
+}
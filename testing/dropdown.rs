@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::dropdown::*;
+
+fn spawn_colors(app: &mut WidgetTestApp) -> Entity {
+    app.spawn_dropdown(
+        DropdownBuilder::new()
+            .with_option("Red", None)
+            .with_option("Green", None)
+            .with_placeholder("Pick a color")
+            .build(),
+    )
+}
+
+fn open(app: &mut WidgetTestApp) {
+    let button = app.entities::<With<DropdownButton>>()[0];
+    app.click(button);
+}
+
+#[test]
+fn pressing_the_button_opens_the_list() {
+    let mut app = WidgetTestApp::new();
+    let dropdown = spawn_colors(&mut app);
+
+    open(&mut app);
+
+    assert!(app.get::<Dropdown>(dropdown).is_open);
+    let events = app.take_events::<DropdownChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].dropdown_entity, dropdown);
+    assert_eq!(events[0].kind, DropdownChangeKind::Opened);
+}
+
+#[test]
+fn choosing_an_option_reports_its_label() {
+    let mut app = WidgetTestApp::new();
+    let dropdown = spawn_colors(&mut app);
+    open(&mut app);
+    app.take_events::<DropdownChangedEvent>();
+
+    let green = app.entities::<With<DropdownOptionElement>>()[1];
+    app.click(green);
+
+    let dropdown_state = app.get::<Dropdown>(dropdown);
+    assert!(!dropdown_state.is_open);
+    assert_eq!(dropdown_state.selected_id, Some(app.get::<DropdownOptionElement>(green).0));
+    let events = app.take_events::<DropdownChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, DropdownChangeKind::SelectionChanged);
+    assert_eq!(events[0].previous_label, None);
+    assert_eq!(events[0].new_label.as_deref(), Some("Green"));
+}
+
+#[test]
+fn escape_cancels_an_open_dropdown() {
+    let mut app = WidgetTestApp::new();
+    let dropdown = spawn_colors(&mut app);
+    open(&mut app);
+    app.step(5);
+    app.take_events::<DropdownChangedEvent>();
+
+    app.tap_key(KeyCode::Escape);
+
+    assert!(!app.get::<Dropdown>(dropdown).is_open);
+    assert_eq!(app.get::<Dropdown>(dropdown).selected_id, None);
+    let events = app.take_events::<DropdownChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, DropdownChangeKind::Cancelled);
+}
+
+#[test]
+fn keyboard_opens_the_list_and_picks_the_highlighted_option() {
+    let mut app = WidgetTestApp::new();
+    let dropdown = spawn_colors(&mut app);
+    let ids = app.get::<Dropdown>(dropdown).option_ids.clone();
+    app.tap_key(KeyCode::Tab);
+
+    app.tap_key(KeyCode::ArrowDown);
+    assert!(app.get::<Dropdown>(dropdown).is_open);
+    assert_eq!(app.get::<DropdownHighlight>(dropdown).0, Some(ids[0]));
+
+    app.tap_key(KeyCode::ArrowDown);
+    app.tap_key(KeyCode::ArrowDown);
+    assert_eq!(app.get::<DropdownHighlight>(dropdown).0, Some(ids[1]));
+    app.tap_key(KeyCode::Enter);
+
+    let dropdown_state = app.get::<Dropdown>(dropdown);
+    assert!(!dropdown_state.is_open);
+    assert_eq!(dropdown_state.selected_id, Some(ids[1]));
+    let kinds: Vec<_> = app.take_events::<DropdownChangedEvent>().iter().map(|event| event.kind).collect();
+    assert_eq!(kinds, [DropdownChangeKind::Opened, DropdownChangeKind::SelectionChanged]);
+}
+
+#[test]
+fn select_dropdown_option_reports_labels() {
+    let mut app = WidgetTestApp::new();
+    let dropdown = spawn_colors(&mut app);
+    let ids = app.get::<Dropdown>(dropdown).option_ids.clone();
+
+    app.run_commands(|commands| {
+        commands.entity(dropdown).select_dropdown_option(Some(ids[0]));
+    });
+    app.update();
+
+    assert_eq!(app.get::<Dropdown>(dropdown).selected_id, Some(ids[0]));
+    let events = app.take_events::<DropdownChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, DropdownChangeKind::Programmatic);
+    assert_eq!(events[0].new_label.as_deref(), Some("Red"));
+
+    app.run_commands(|commands| {
+        commands.entity(dropdown).select_dropdown_option_silently(None);
+    });
+    app.update();
+
+    assert_eq!(app.get::<Dropdown>(dropdown).selected_id, None);
+    assert!(app.take_events::<DropdownChangedEvent>().is_empty());
+}
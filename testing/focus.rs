@@ -0,0 +1,200 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::dropdown::DropdownBuilder;
+use crate::focus::*;
+use crate::slider::SliderBuilder;
+use crate::toggle::ToggleBuilder;
+
+fn spawn_toggle(app: &mut WidgetTestApp) -> Entity {
+    app.spawn_with(|commands| ToggleBuilder::new(commands).spawn())
+}
+
+fn focused(app: &mut WidgetTestApp) -> Option<Entity> {
+    app.world().resource::<WidgetFocus>().get()
+}
+
+fn shift_tab(app: &mut WidgetTestApp) {
+    app.press_key(KeyCode::ShiftLeft).update();
+    app.tap_key(KeyCode::Tab);
+    app.release_key(KeyCode::ShiftLeft).update();
+}
+
+#[test]
+fn tab_walks_every_widget_type_and_wraps() {
+    let mut app = WidgetTestApp::new();
+    let toggle = spawn_toggle(&mut app);
+    let slider = app.spawn_with(|commands| SliderBuilder::new(commands).spawn());
+    let dropdown = app.spawn_dropdown(DropdownBuilder::new().with_option("Red", None).build());
+
+    for expected in [toggle, slider, dropdown, toggle] {
+        app.tap_key(KeyCode::Tab);
+        assert_eq!(focused(&mut app), Some(expected));
+        assert!(app.has::<WidgetFocused>(expected));
+    }
+    assert_eq!(app.entities::<With<WidgetFocused>>(), vec![toggle]);
+
+    shift_tab(&mut app);
+    assert_eq!(focused(&mut app), Some(dropdown));
+}
+
+#[test]
+fn explicit_tab_indices_come_first_and_negative_ones_are_skipped() {
+    let mut app = WidgetTestApp::new();
+    let plain = spawn_toggle(&mut app);
+    let skipped = app.spawn_with(|commands| ToggleBuilder::new(commands).with_tab_index(-1).spawn());
+    let second = app.spawn_with(|commands| ToggleBuilder::new(commands).with_tab_index(2).spawn());
+    let first = app.spawn_with(|commands| ToggleBuilder::new(commands).with_tab_index(1).spawn());
+
+    let mut order = Vec::new();
+    for _ in 0..3 {
+        app.tap_key(KeyCode::Tab);
+        order.extend(focused(&mut app));
+    }
+    assert_eq!(order, vec![first, second, plain]);
+
+    // Still reachable by clicking
+    app.click(skipped);
+    assert_eq!(focused(&mut app), Some(skipped));
+}
+
+#[test]
+fn equal_tab_indices_follow_reading_order() {
+    let mut app = WidgetTestApp::new();
+    let lower = spawn_toggle(&mut app);
+    let upper_right = spawn_toggle(&mut app);
+    let upper_left = spawn_toggle(&mut app);
+    app.set_layout(lower, Vec2::new(50.0, 200.0), Vec2::new(50.0, 25.0));
+    app.set_layout(upper_right, Vec2::new(150.0, 100.0), Vec2::new(50.0, 25.0));
+    app.set_layout(upper_left, Vec2::new(50.0, 100.0), Vec2::new(50.0, 25.0));
+
+    let mut order = Vec::new();
+    for _ in 0..3 {
+        app.tap_key(KeyCode::Tab);
+        order.extend(focused(&mut app));
+    }
+    assert_eq!(order, vec![upper_left, upper_right, lower]);
+}
+
+#[test]
+fn slightly_misaligned_widgets_share_a_row() {
+    let mut app = WidgetTestApp::new();
+    let right = spawn_toggle(&mut app);
+    let left = spawn_toggle(&mut app);
+    let below = spawn_toggle(&mut app);
+    app.set_layout(right, Vec2::new(150.0, 100.0), Vec2::new(50.0, 25.0));
+    app.set_layout(left, Vec2::new(50.0, 104.0), Vec2::new(50.0, 18.0));
+    app.set_layout(below, Vec2::new(10.0, 140.0), Vec2::new(50.0, 25.0));
+
+    let mut order = Vec::new();
+    for _ in 0..3 {
+        app.tap_key(KeyCode::Tab);
+        order.extend(focused(&mut app));
+    }
+    assert_eq!(order, vec![left, right, below]);
+}
+
+#[test]
+fn hidden_widgets_are_skipped() {
+    let mut app = WidgetTestApp::new();
+    let visible = spawn_toggle(&mut app);
+    let panel = app.spawn_with(|commands| commands.spawn((Node::default(), Visibility::Hidden)).id());
+    let hidden = spawn_toggle(&mut app);
+    app.world().entity_mut(panel).add_child(hidden);
+
+    app.tap_key(KeyCode::Tab);
+    app.tap_key(KeyCode::Tab);
+
+    assert_eq!(focused(&mut app), Some(visible));
+}
+
+#[test]
+fn focus_scope_traps_tab_and_restores_focus_when_removed() {
+    let mut app = WidgetTestApp::new();
+    let outside = spawn_toggle(&mut app);
+    let panel = app.spawn_with(|commands| commands.spawn(Node::default()).id());
+    let inside = [spawn_toggle(&mut app), spawn_toggle(&mut app)];
+    app.world().entity_mut(panel).add_children(&inside);
+    app.tap_key(KeyCode::Tab);
+    assert_eq!(focused(&mut app), Some(outside));
+
+    app.world().entity_mut(panel).insert(FocusScope);
+    app.update();
+    assert_eq!(focused(&mut app), Some(inside[0]));
+
+    app.tap_key(KeyCode::Tab);
+    assert_eq!(focused(&mut app), Some(inside[1]));
+    app.tap_key(KeyCode::Tab);
+    assert_eq!(focused(&mut app), Some(inside[0]));
+
+    // Clicking outside the scope leaves focus inside it
+    app.click(outside);
+    assert_eq!(focused(&mut app), Some(inside[0]));
+
+    app.world().entity_mut(panel).remove::<FocusScope>();
+    app.update();
+    assert_eq!(focused(&mut app), Some(outside));
+}
+
+#[test]
+fn despawning_the_focused_widget_clears_focus() {
+    let mut app = WidgetTestApp::new();
+    let toggle = spawn_toggle(&mut app);
+    app.tap_key(KeyCode::Tab);
+
+    app.world().entity_mut(toggle).despawn();
+    app.update();
+
+    assert_eq!(focused(&mut app), None);
+}
+
+fn rings(app: &mut WidgetTestApp) -> Vec<Entity> {
+    let rings = app.entities::<With<FocusRing>>();
+    rings.into_iter().map(|ring| app.get::<FocusRing>(ring).target).collect()
+}
+
+#[test]
+fn keyboard_focus_shows_a_ring_around_the_ring_target() {
+    let mut app = WidgetTestApp::new();
+    let slider = app.spawn_with(|commands| SliderBuilder::new(commands).spawn());
+    let handle = app.get::<crate::slider::components::Slider>(slider).handle_entity;
+
+    app.tap_key(KeyCode::Tab);
+
+    assert_eq!(rings(&mut app), vec![handle]);
+    let ring = app.entities::<With<FocusRing>>()[0];
+    assert_eq!(app.get::<ChildOf>(ring).parent(), handle);
+    let style = app.world().resource::<crate::theme::WidgetTheme>().focus_ring.clone();
+    assert_eq!(app.get::<BorderColor>(ring).0, style.color);
+    assert_eq!(app.get::<Node>(ring).left, Val::Px(-(style.offset + style.width)));
+}
+
+#[test]
+fn ring_follows_focus_and_hides_after_a_click() {
+    let mut app = WidgetTestApp::new();
+    let first = spawn_toggle(&mut app);
+    let second = spawn_toggle(&mut app);
+
+    app.tap_key(KeyCode::Tab);
+    assert_eq!(rings(&mut app), vec![first]);
+    app.tap_key(KeyCode::Tab);
+    assert_eq!(rings(&mut app), vec![second]);
+
+    app.click(first);
+    assert_eq!(focused(&mut app), Some(first));
+    assert!(rings(&mut app).is_empty());
+}
+
+#[test]
+fn ring_restyles_with_the_theme() {
+    let mut app = WidgetTestApp::new();
+    spawn_toggle(&mut app);
+    app.tap_key(KeyCode::Tab);
+
+    let color = Color::srgb(1.0, 0.5, 0.0);
+    app.world().resource_mut::<crate::theme::WidgetTheme>().focus_ring.color = color;
+    app.update();
+
+    let ring = app.entities::<With<FocusRing>>()[0];
+    assert_eq!(app.get::<BorderColor>(ring).0, color);
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::knob::*;
+use crate::slider::{components::Slider, SliderValueChangedEvent};
+
+#[test]
+fn dragging_up_raises_the_value() {
+    let mut app = WidgetTestApp::new();
+    let knob = app.spawn_with(|commands| {
+        KnobBuilder::new(commands)
+            .with_range(0.0, 100.0)
+            .with_value(50.0)
+            .with_drag_mode(KnobDragMode::Vertical { pixels_per_range: 200.0 })
+            .spawn()
+    });
+    let dial = app.get::<KnobParts>(knob).dial;
+    app.set_layout(dial, Vec2::new(100.0, 100.0), Vec2::splat(60.0));
+
+    app.move_cursor(Vec2::new(100.0, 100.0)).update();
+    app.set_interaction(dial, Interaction::Pressed)
+        .press_mouse(MouseButton::Left)
+        .update();
+    app.move_cursor(Vec2::new(100.0, 50.0)).update();
+
+    assert_eq!(app.get::<Slider>(knob).value, 75.0);
+
+    // Leaving the dial mid-drag keeps the gesture going
+    app.set_interaction(dial, Interaction::None)
+        .move_cursor(Vec2::new(100.0, 0.0))
+        .update();
+    assert_eq!(app.get::<Slider>(knob).value, 100.0);
+
+    app.release_mouse(MouseButton::Left).update();
+    app.move_cursor(Vec2::new(100.0, 200.0)).update();
+    assert_eq!(app.get::<Slider>(knob).value, 100.0);
+    assert_eq!(app.take_events::<SliderValueChangedEvent>().len(), 2);
+}
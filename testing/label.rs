@@ -0,0 +1,141 @@
+use accesskit::NodeId;
+use bevy::a11y::AccessibilityNode;
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::focus::WidgetFocus;
+use crate::label::*;
+use crate::slider::*;
+use crate::toggle::*;
+
+fn spawn_labelled_toggle(app: &mut WidgetTestApp, position: LabelPosition) -> Entity {
+    app.spawn_with(|commands| {
+        ToggleBuilder::new(commands)
+            .with_label("Notifications", position)
+            .with_description("Sent at most once a day")
+            .spawn()
+    })
+}
+
+fn container_children(app: &mut WidgetTestApp, control: Entity) -> (FlexDirection, Vec<Entity>) {
+    let container = app.get::<WidgetLabels>(control).container;
+    let children = app.get::<Children>(container).to_vec();
+    (app.get::<Node>(container).flex_direction, children)
+}
+
+#[test]
+fn label_position_orders_the_control_and_its_text() {
+    let mut app = WidgetTestApp::new();
+    let left = spawn_labelled_toggle(&mut app, LabelPosition::Left);
+    let below = spawn_labelled_toggle(&mut app, LabelPosition::Below);
+
+    let (direction, children) = container_children(&mut app, left);
+    assert_eq!(direction, FlexDirection::Row);
+    assert_eq!(children[1], left);
+
+    let (direction, children) = container_children(&mut app, below);
+    assert_eq!(direction, FlexDirection::Column);
+    assert_eq!(children[0], below);
+}
+
+#[test]
+fn clicking_a_toggle_label_flips_and_focuses_it() {
+    let mut app = WidgetTestApp::new();
+    let toggle = spawn_labelled_toggle(&mut app, LabelPosition::Left);
+    let label = app.get::<WidgetLabels>(toggle).label.unwrap();
+
+    app.click(label);
+
+    assert!(app.get::<Toggle>(toggle).is_on());
+    assert_eq!(app.world().resource::<WidgetFocus>().get(), Some(toggle));
+    let events = app.take_events::<ToggleChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ToggleChangeKind::User);
+}
+
+#[test]
+fn disabled_toggle_ignores_its_label() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| {
+        ToggleBuilder::new(commands)
+            .with_label("Locked", LabelPosition::Right)
+            .disabled(true)
+            .spawn()
+    });
+    let label = app.get::<WidgetLabels>(toggle).label.unwrap();
+
+    app.click(label);
+
+    assert!(!app.get::<Toggle>(toggle).is_on());
+    assert!(app.take_events::<ToggleChangedEvent>().is_empty());
+}
+
+#[test]
+fn clicking_a_slider_label_focuses_the_slider() {
+    let mut app = WidgetTestApp::new();
+    let slider = app.spawn_with(|commands| {
+        SliderBuilder::new(commands)
+            .with_label("Volume", LabelPosition::Above)
+            .spawn()
+    });
+    let label = app.get::<WidgetLabels>(slider).label.unwrap();
+
+    app.click(label);
+
+    assert_eq!(app.world().resource::<WidgetFocus>().get(), Some(slider));
+}
+
+#[test]
+fn controls_are_labelled_and_described_for_accessibility() {
+    let mut app = WidgetTestApp::new();
+    let toggle = spawn_labelled_toggle(&mut app, LabelPosition::Left);
+    app.update();
+    let labels = app.get::<WidgetLabels>(toggle).clone();
+
+    let node = app.get::<AccessibilityNode>(toggle);
+    assert_eq!(node.labelled_by(), [NodeId(labels.label.unwrap().to_bits())]);
+    assert_eq!(node.described_by(), [NodeId(labels.description.unwrap().to_bits())]);
+}
+
+#[test]
+fn description_uses_the_smaller_theme_font() {
+    let mut app = WidgetTestApp::new();
+    let toggle = spawn_labelled_toggle(&mut app, LabelPosition::Left);
+    app.update();
+    let labels = app.get::<WidgetLabels>(toggle).clone();
+
+    assert_eq!(app.get::<TextFont>(labels.label.unwrap()).font_size, 14.0);
+    assert_eq!(app.get::<TextFont>(labels.description.unwrap()).font_size, 12.0);
+}
+
+#[test]
+fn toggle_label_acts_on_release_and_cancels_when_dragged_off() {
+    let mut app = WidgetTestApp::new();
+    let toggle = spawn_labelled_toggle(&mut app, LabelPosition::Left);
+    let label = app.get::<WidgetLabels>(toggle).label.unwrap();
+
+    app.set_interaction(label, Interaction::Pressed).press_mouse(MouseButton::Left).update();
+    assert!(!app.get::<Toggle>(toggle).is_on());
+
+    app.set_interaction(label, Interaction::None).release_mouse(MouseButton::Left).update();
+    assert!(!app.get::<Toggle>(toggle).is_on());
+    assert!(app.take_events::<ToggleChangedEvent>().is_empty());
+}
+
+#[test]
+fn spawn_with_parent_places_the_label_container() {
+    let mut app = WidgetTestApp::new();
+    let panel = app.world().spawn(Node::default()).id();
+    let toggle = app.spawn_with(|commands| {
+        ToggleBuilder::new(commands)
+            .with_label("Notifications", LabelPosition::Left)
+            .spawn_with_parent(panel)
+    });
+    let checkbox = app.spawn_with(|commands| {
+        CheckboxBuilder::new(commands).spawn_with_parent(panel)
+    });
+    let container = app.get::<WidgetLabels>(toggle).container;
+
+    assert_eq!(app.get::<Children>(panel).to_vec(), [container, checkbox]);
+    assert_eq!(app.get::<ChildOf>(toggle).parent(), container);
+}
@@ -0,0 +1,333 @@
+//! Headless harness for driving widgets without a window or GPU.
+//!
+//! `WidgetTestApp` wires the widget plugins into a minimal `App` with a fixed
+//! frame time. UI picking and layout don't run headless, so tests set
+//! `Interaction` and node geometry directly and feed raw input events through
+//! Bevy's input plugin, exactly as winit would.
+
+use bevy::app::Plugins;
+use bevy::ecs::component::Mutable;
+use bevy::ecs::query::QueryFilter;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey, NativeKeyCode};
+use bevy::input::mouse::{MouseButtonInput, MouseScrollUnit, MouseWheel};
+use bevy::input::{ButtonState, InputPlugin};
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy::ui::ComputedNode;
+use std::time::Duration;
+
+use crate::dropdown::{builder::DropdownSpawnCommand, DropdownChangedEvent, DropdownOptionRegistry};
+use crate::progress_bar::ProgressChangedEvent;
+use crate::radio_group::RadioChangedEvent;
+use crate::slider::{SliderCommittedEvent, SliderValueChangedEvent};
+use crate::tab_group::TabChangedEvent;
+use crate::toggle::ToggleChangedEvent;
+
+#[cfg(test)]
+mod accessibility;
+#[cfg(test)]
+mod anim;
+#[cfg(test)]
+mod binding;
+#[cfg(test)]
+mod checkbox;
+#[cfg(test)]
+mod dropdown;
+#[cfg(test)]
+mod focus;
+#[cfg(test)]
+mod knob;
+#[cfg(test)]
+mod label;
+#[cfg(test)]
+mod motion;
+#[cfg(test)]
+mod plugins;
+#[cfg(test)]
+mod progress_bar;
+#[cfg(test)]
+mod radio_group;
+#[cfg(test)]
+mod slider;
+#[cfg(test)]
+mod stylesheet;
+#[cfg(test)]
+mod tab_group;
+#[cfg(test)]
+mod theme;
+#[cfg(test)]
+mod toggle;
+
+/// Length of one simulated frame.
+pub const FRAME: Duration = Duration::from_micros(16_667);
+
+#[derive(Resource)]
+struct RecordedEvents<E: Event>(Vec<E>);
+
+fn record_events_system<E: Event + Clone>(
+    mut reader: EventReader<E>,
+    mut recorded: ResMut<RecordedEvents<E>>,
+) {
+    recorded.0.extend(reader.read().cloned());
+}
+
+pub struct WidgetTestApp {
+    app: App,
+}
+
+impl Default for WidgetTestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WidgetTestApp {
+    /// Minimal app with every widget plugin.
+    pub fn new() -> Self {
+        Self::with_plugins(crate::WidgetsPlugin)
+    }
+
+    /// Minimal app with only the given widget plugins.
+    pub fn with_plugins<M>(plugins: impl Plugins<M>) -> Self {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .add_plugins(plugins);
+        app.finish();
+        app.cleanup();
+
+        let mut harness = Self { app };
+        harness
+            .record_events::<ToggleChangedEvent>()
+            .record_events::<SliderValueChangedEvent>()
+            .record_events::<SliderCommittedEvent>()
+            .record_events::<DropdownChangedEvent>()
+            .record_events::<TabChangedEvent>()
+            .record_events::<ProgressChangedEvent>()
+            .record_events::<RadioChangedEvent>();
+        // The first frame only initialises time
+        harness.update();
+        harness
+    }
+
+    pub fn app(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    pub fn world(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    /// Starts collecting `E` so it can be fetched with [`Self::take_events`].
+    pub fn record_events<E: Event + Clone>(&mut self) -> &mut Self {
+        if !self.app.world().contains_resource::<RecordedEvents<E>>() {
+            self.app
+                .insert_resource(RecordedEvents::<E>(Vec::new()))
+                .add_systems(Last, record_events_system::<E>);
+        }
+        self
+    }
+
+    /// Every recorded `E` since the last call.
+    pub fn take_events<E: Event + Clone>(&mut self) -> Vec<E> {
+        let mut recorded = self
+            .world()
+            .get_resource_mut::<RecordedEvents<E>>()
+            .expect("call record_events::<E>() before taking events");
+        std::mem::take(&mut recorded.0)
+    }
+
+    /// Runs a builder against `Commands` and applies the result immediately.
+    pub fn spawn_with(&mut self, build: impl FnOnce(&mut Commands) -> Entity) -> Entity {
+        let world = self.world();
+        let entity = {
+            let mut commands = world.commands();
+            build(&mut commands)
+        };
+        world.flush();
+        entity
+    }
+
+    /// Queues commands as a system would and applies them immediately.
+    pub fn run_commands(&mut self, run: impl FnOnce(&mut Commands)) -> &mut Self {
+        let world = self.world();
+        run(&mut world.commands());
+        world.flush();
+        self
+    }
+
+    pub fn spawn_dropdown(&mut self, dropdown: DropdownSpawnCommand) -> Entity {
+        let world = self.world();
+        let entity = world.resource_scope(|world, mut registry: Mut<DropdownOptionRegistry>| {
+            let mut commands = world.commands();
+            dropdown.spawn(&mut commands, &mut registry)
+        });
+        world.flush();
+        entity
+    }
+
+    pub fn update(&mut self) -> &mut Self {
+        self.app.update();
+        self
+    }
+
+    pub fn step(&mut self, frames: usize) -> &mut Self {
+        for _ in 0..frames {
+            self.app.update();
+        }
+        self
+    }
+
+    pub fn entities<F: QueryFilter>(&mut self) -> Vec<Entity> {
+        let world = self.world();
+        let mut query = world.query_filtered::<Entity, F>();
+        let mut entities: Vec<Entity> = query.iter(world).collect();
+        entities.sort();
+        entities
+    }
+
+    pub fn get<C: Component>(&self, entity: Entity) -> &C {
+        self.app
+            .world()
+            .get::<C>(entity)
+            .unwrap_or_else(|| panic!("{entity:?} has no {}", std::any::type_name::<C>()))
+    }
+
+    pub fn get_mut<C: Component<Mutability = Mutable>>(&mut self, entity: Entity) -> Mut<'_, C> {
+        self.world()
+            .get_mut::<C>(entity)
+            .unwrap_or_else(|| panic!("{entity:?} has no {}", std::any::type_name::<C>()))
+    }
+
+    pub fn has<C: Component>(&self, entity: Entity) -> bool {
+        self.app.world().get::<C>(entity).is_some()
+    }
+
+    /// Stands in for UI picking by writing `Interaction` directly.
+    pub fn set_interaction(&mut self, entity: Entity, interaction: Interaction) -> &mut Self {
+        self.world().entity_mut(entity).insert(interaction);
+        self
+    }
+
+    /// Press-and-release on a button-like entity over two frames.
+    pub fn click(&mut self, entity: Entity) -> &mut Self {
+        self.set_interaction(entity, Interaction::Pressed)
+            .press_mouse(MouseButton::Left)
+            .update();
+        self.set_interaction(entity, Interaction::Hovered)
+            .release_mouse(MouseButton::Left)
+            .update()
+    }
+
+    /// Gives a node the size and position layout would, in logical pixels
+    /// with a scale factor of 1.
+    pub fn set_layout(&mut self, entity: Entity, center: Vec2, size: Vec2) -> &mut Self {
+        self.world().entity_mut(entity).insert((
+            ComputedNode {
+                size,
+                unrounded_size: size,
+                inverse_scale_factor: 1.0,
+                ..default()
+            },
+            GlobalTransform::from_translation(center.extend(0.0)),
+        ));
+        self
+    }
+
+    pub fn press_mouse(&mut self, button: MouseButton) -> &mut Self {
+        self.world().send_event(MouseButtonInput {
+            button,
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        });
+        self
+    }
+
+    pub fn release_mouse(&mut self, button: MouseButton) -> &mut Self {
+        self.world().send_event(MouseButtonInput {
+            button,
+            state: ButtonState::Released,
+            window: Entity::PLACEHOLDER,
+        });
+        self
+    }
+
+    pub fn move_cursor(&mut self, position: Vec2) -> &mut Self {
+        self.world().send_event(CursorMoved {
+            window: Entity::PLACEHOLDER,
+            position,
+            delta: None,
+        });
+        self
+    }
+
+    pub fn scroll(&mut self, lines: f32) -> &mut Self {
+        self.world().send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: 0.0,
+            y: lines,
+            window: Entity::PLACEHOLDER,
+        });
+        self
+    }
+
+    pub fn press_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.send_key(key_code, logical_key(key_code), ButtonState::Pressed)
+    }
+
+    pub fn release_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.send_key(key_code, logical_key(key_code), ButtonState::Released)
+    }
+
+    /// Press a key for one frame, then release it on the next.
+    pub fn tap_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.press_key(key_code).update();
+        self.release_key(key_code).update()
+    }
+
+    /// Sends one character key press per char, all in the next frame.
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for character in text.chars() {
+            let key = Key::Character(character.to_string().into());
+            let key_code = KeyCode::Unidentified(NativeKeyCode::Unidentified);
+            self.send_key(key_code, key, ButtonState::Pressed);
+        }
+        self
+    }
+
+    fn send_key(&mut self, key_code: KeyCode, logical_key: Key, state: ButtonState) -> &mut Self {
+        let text = match (&logical_key, state) {
+            (Key::Character(text), ButtonState::Pressed) => Some(text.clone()),
+            _ => None,
+        };
+        self.world().send_event(KeyboardInput {
+            key_code,
+            logical_key,
+            state,
+            text,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+        self
+    }
+}
+
+fn logical_key(key_code: KeyCode) -> Key {
+    match key_code {
+        KeyCode::Space => Key::Space,
+        KeyCode::Enter | KeyCode::NumpadEnter => Key::Enter,
+        KeyCode::Escape => Key::Escape,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::ArrowLeft => Key::ArrowLeft,
+        KeyCode::ArrowRight => Key::ArrowRight,
+        KeyCode::ArrowUp => Key::ArrowUp,
+        KeyCode::ArrowDown => Key::ArrowDown,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => Key::Shift,
+        _ => Key::Unidentified(NativeKey::Unidentified),
+    }
+}
@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::dropdown::*;
+use crate::motion::*;
+use crate::progress_bar::*;
+use crate::toggle::*;
+
+fn reduced_motion_app() -> WidgetTestApp {
+    let mut app = WidgetTestApp::new();
+    app.world().resource_mut::<WidgetMotionSettings>().reduced_motion = true;
+    app
+}
+
+fn knob_left(app: &mut WidgetTestApp, toggle: Entity) -> Val {
+    let knob = app.get::<ToggleParts>(toggle).knob;
+    app.get::<Node>(knob).left
+}
+
+#[test]
+fn toggle_knob_follows_the_animation() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+    app.update();
+    let off = knob_left(&mut app, toggle);
+
+    app.click(toggle);
+    let moving = knob_left(&mut app, toggle);
+    app.step(60);
+
+    assert_ne!(moving, off);
+    assert_ne!(knob_left(&mut app, toggle), moving);
+}
+
+#[test]
+fn reduced_motion_snaps_toggles() {
+    let mut app = reduced_motion_app();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+
+    app.click(toggle);
+
+    assert_eq!(app.get::<ToggleAnimation>(toggle).progress.value(), 1.0);
+}
+
+#[test]
+fn speed_scales_animations() {
+    let progress_after_one_frame = |speed: f32| {
+        let mut app = WidgetTestApp::new();
+        app.world().resource_mut::<WidgetMotionSettings>().speed = speed;
+        let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+        app.click(toggle);
+        app.get::<ToggleAnimation>(toggle).progress.value()
+    };
+
+    let normal = progress_after_one_frame(1.0);
+    assert!(normal > 0.0 && normal < 1.0);
+    assert!(progress_after_one_frame(2.0) > normal);
+    assert_eq!(progress_after_one_frame(0.0), 1.0);
+}
+
+#[test]
+fn reduced_motion_opens_dropdowns_at_full_height() {
+    let mut app = reduced_motion_app();
+    let dropdown = app.spawn_dropdown(DropdownBuilder::new().with_option("Red", None).build());
+    let button = app.entities::<With<DropdownButton>>()[0];
+    let list = app.entities::<With<DropdownList>>()[0];
+
+    app.click(button);
+
+    assert!(app.get::<Dropdown>(dropdown).is_open);
+    assert_eq!(app.get::<DropdownAnimation>(dropdown).progress.value(), 1.0);
+    assert_eq!(app.get::<Node>(list).height, Val::Px(200.0));
+    assert_eq!(app.get::<Visibility>(list), &Visibility::Visible);
+}
+
+#[test]
+fn reduced_motion_still_reports_progress_completion() {
+    let mut app = reduced_motion_app();
+    app.record_events::<ProgressCompletedEvent>();
+    let bar = app.spawn_with(|commands| ProgressBarBuilder::new(commands).with_value(0.0, 100.0).spawn());
+    app.update();
+
+    app.get_mut::<ProgressBar>(bar).set_value(100.0);
+    app.update();
+
+    let completed = app.take_events::<ProgressCompletedEvent>();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].progress_entity, bar);
+}
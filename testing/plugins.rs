@@ -0,0 +1,55 @@
+use bevy::app::Plugins;
+use bevy::prelude::*;
+
+use crate::dropdown::DropdownPlugin;
+use crate::knob::KnobPlugin;
+use crate::progress_bar::ProgressBarPlugin;
+use crate::radio_group::RadioPlugin;
+use crate::slider::SliderPlugin;
+use crate::tab_group::TabPlugin;
+use crate::toggle::TogglePlugin;
+use crate::*;
+
+/// Builds `plugins` into an otherwise empty app and runs a frame.
+fn build_blank<M>(plugins: impl Plugins<M>) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, bevy::input::InputPlugin)).add_plugins(plugins);
+    app.finish();
+    app.cleanup();
+    app.update();
+}
+
+#[test]
+fn widgets_plugin_builds_on_a_blank_app() {
+    let mut app = App::new();
+    app.add_plugins(WidgetsPlugin);
+    app.finish();
+    app.cleanup();
+}
+
+#[test]
+fn widgets_plugin_runs_a_frame() {
+    build_blank(WidgetsPlugin);
+}
+
+#[test]
+fn every_plugin_builds_and_runs_on_its_own() {
+    build_blank(WidgetInputPlugin);
+    build_blank(WidgetThemePlugin);
+    build_blank(WidgetFocusPlugin);
+    build_blank(WidgetBindingPlugin);
+    build_blank(WidgetMotionPlugin);
+    build_blank(WidgetLabelPlugin);
+    build_blank(TogglePlugin);
+    build_blank(SliderPlugin);
+    build_blank(KnobPlugin);
+    build_blank(DropdownPlugin);
+    build_blank(TabPlugin);
+    build_blank(ProgressBarPlugin);
+    build_blank(RadioPlugin);
+}
+
+#[test]
+fn the_style_sheet_plugin_builds_beside_the_group() {
+    build_blank((bevy::asset::AssetPlugin::default(), WidgetsPlugin, WidgetStyleSheetPlugin));
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::progress_bar::*;
+
+fn spawn_bar(app: &mut WidgetTestApp) -> Entity {
+    let bar = app.spawn_with(|commands| ProgressBarBuilder::new(commands).with_value(0.0, 100.0).spawn());
+    // Drop the event for the initial value
+    app.update();
+    app.take_events::<ProgressChangedEvent>();
+    bar
+}
+
+#[test]
+fn setting_the_value_reports_the_change() {
+    let mut app = WidgetTestApp::new();
+    let bar = spawn_bar(&mut app);
+
+    app.get_mut::<ProgressBar>(bar).set_value(5.0);
+    app.update();
+
+    let events = app.take_events::<ProgressChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].progress_entity, bar);
+    assert_eq!(events[0].previous_value, 0.0);
+    assert_eq!(events[0].new_value, 5.0);
+    assert_eq!(events[0].change_kind, ProgressChangeKind::Incremental);
+}
+
+#[test]
+fn values_are_clamped_to_the_range() {
+    let mut app = WidgetTestApp::new();
+    let bar = spawn_bar(&mut app);
+
+    app.get_mut::<ProgressBar>(bar).set_value(250.0);
+    app.update();
+
+    assert_eq!(app.get::<ProgressBar>(bar).current, 100.0);
+    let events = app.take_events::<ProgressChangedEvent>();
+    assert_eq!(events[0].change_kind, ProgressChangeKind::Complete);
+    assert!(app.has::<ProgressCompleted>(bar));
+}
+
+#[test]
+fn completion_fires_once_the_fill_animation_ends() {
+    let mut app = WidgetTestApp::new();
+    app.record_events::<ProgressCompletedEvent>();
+    let bar = spawn_bar(&mut app);
+
+    app.get_mut::<ProgressBar>(bar).set_value(100.0);
+    app.update();
+    assert!(app.take_events::<ProgressCompletedEvent>().is_empty());
+
+    app.step(60);
+    let completed = app.take_events::<ProgressCompletedEvent>();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].progress_entity, bar);
+}
@@ -0,0 +1,153 @@
+use accesskit::{Role, Toggled};
+use bevy::a11y::AccessibilityNode;
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::focus::{Focusable, WidgetFocus};
+use crate::radio_group::*;
+
+fn spawn_radios(app: &mut WidgetTestApp, allow_none: bool) -> Entity {
+    app.spawn_with(|commands| {
+        RadioGroupBuilder::new(commands)
+            .with_option("Small")
+            .with_option("Medium")
+            .with_option("Large")
+            .allow_none(allow_none)
+            .spawn()
+    })
+}
+
+fn option(app: &mut WidgetTestApp, group: Entity, index: usize) -> Entity {
+    app.get::<RadioGroupMeta>(group).option_entities[index]
+}
+
+#[test]
+fn first_option_starts_selected_unless_none_is_allowed() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_radios(&mut app, false);
+    let optional = spawn_radios(&mut app, true);
+
+    assert_eq!(app.get::<RadioGroup>(group).selected, Some(0));
+    assert_eq!(app.get::<RadioGroup>(optional).selected, None);
+}
+
+#[test]
+fn click_selects_exclusively_and_reports_both_indices() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_radios(&mut app, false);
+    let large = option(&mut app, group, 2);
+
+    app.click(large);
+
+    assert_eq!(app.get::<RadioGroup>(group).selected, Some(2));
+    let events = app.take_events::<RadioChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].previous_index, Some(0));
+    assert_eq!(events[0].new_index, Some(2));
+    assert_eq!(events[0].kind, RadioChangeKind::User);
+}
+
+#[test]
+fn clicking_the_selected_option_clears_it_only_when_allowed() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_radios(&mut app, false);
+    let optional = spawn_radios(&mut app, true);
+    let first = option(&mut app, group, 0);
+    let optional_first = option(&mut app, optional, 0);
+
+    app.click(first);
+    app.click(optional_first);
+    app.click(optional_first);
+
+    assert_eq!(app.get::<RadioGroup>(group).selected, Some(0));
+    assert_eq!(app.get::<RadioGroup>(optional).selected, None);
+    let events = app.take_events::<RadioChangedEvent>();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].new_index, None);
+}
+
+#[test]
+fn arrow_keys_move_focus_and_selection_with_wrapping() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_radios(&mut app, false);
+    let first = option(&mut app, group, 0);
+    let last = option(&mut app, group, 2);
+    app.world().resource_mut::<WidgetFocus>().set(first);
+
+    app.tap_key(KeyCode::ArrowUp);
+
+    assert_eq!(app.get::<RadioGroup>(group).selected, Some(2));
+    assert_eq!(app.world().resource::<WidgetFocus>().get(), Some(last));
+
+    app.tap_key(KeyCode::ArrowDown);
+
+    assert_eq!(app.get::<RadioGroup>(group).selected, Some(0));
+    assert_eq!(app.take_events::<RadioChangedEvent>().len(), 2);
+}
+
+#[test]
+fn only_the_selected_option_is_in_tab_order() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_radios(&mut app, false);
+    let options = app.get::<RadioGroupMeta>(group).option_entities.clone();
+
+    app.run_commands(|commands| {
+        commands.entity(group).select_radio(1);
+    });
+    app.update();
+
+    let tab_indices: Vec<i32> = options.iter().map(|&entity| app.get::<Focusable>(entity).tab_index).collect();
+    assert_eq!(tab_indices, [-1, 0, -1]);
+    let events = app.take_events::<RadioChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, RadioChangeKind::Programmatic);
+}
+
+#[test]
+fn dot_grows_into_the_selected_option() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_radios(&mut app, false);
+    let medium = option(&mut app, group, 1);
+    let dot = app.get::<RadioOptionParts>(medium).dot;
+    app.update();
+    assert_eq!(app.get::<Node>(dot).width, Val::Percent(0.0));
+
+    app.click(medium);
+    let growing = app.get::<RadioAnimation>(medium).progress.value();
+    app.step(60);
+
+    assert!(growing > 0.0 && growing < 1.0);
+    assert_eq!(app.get::<Node>(dot).width, Val::Percent(50.0));
+}
+
+#[test]
+fn disabled_group_ignores_clicks() {
+    let mut app = WidgetTestApp::new();
+    let group = app.spawn_with(|commands| {
+        RadioGroupBuilder::new(commands)
+            .with_option("On")
+            .with_option("Off")
+            .disabled(true)
+            .spawn()
+    });
+    let off = option(&mut app, group, 1);
+
+    app.click(off);
+
+    assert_eq!(app.get::<RadioGroup>(group).selected, Some(0));
+    assert!(app.take_events::<RadioChangedEvent>().is_empty());
+}
+
+#[test]
+fn options_are_announced_as_radio_buttons() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_radios(&mut app, false);
+    let first = option(&mut app, group, 0);
+    let second = option(&mut app, group, 1);
+    app.update();
+
+    assert_eq!(app.get::<AccessibilityNode>(group).role(), Role::RadioGroup);
+    assert_eq!(app.get::<AccessibilityNode>(first).role(), Role::RadioButton);
+    assert_eq!(app.get::<AccessibilityNode>(first).toggled(), Some(Toggled::True));
+    assert_eq!(app.get::<AccessibilityNode>(second).toggled(), Some(Toggled::False));
+}
@@ -0,0 +1,354 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::focus::WidgetFocused;
+use crate::slider::{components::*, *};
+
+const TRACK_CENTER: Vec2 = Vec2::new(200.0, 100.0);
+const TRACK_SIZE: Vec2 = Vec2::new(200.0, 20.0);
+
+fn spawn_slider(
+    app: &mut WidgetTestApp,
+    build: impl for<'w, 's, 'a> FnOnce(SliderBuilder<'w, 's, 'a>) -> SliderBuilder<'w, 's, 'a>,
+) -> Entity {
+    app.spawn_with(|commands| {
+        build(SliderBuilder::new(commands).with_range(0.0, 100.0).with_value(50.0).with_step(1.0)).spawn()
+    })
+}
+
+/// Lays the track out at `TRACK_CENTER` and returns the handle.
+fn lay_out(app: &mut WidgetTestApp, slider: Entity) -> Entity {
+    let handle = app.get::<Slider>(slider).handle_entity;
+    let track = app.get::<ChildOf>(handle).parent();
+    app.set_layout(track, TRACK_CENTER, TRACK_SIZE);
+    handle
+}
+
+fn track_point(fraction: f32) -> Vec2 {
+    Vec2::new(TRACK_CENTER.x - TRACK_SIZE.x / 2.0 + TRACK_SIZE.x * fraction, TRACK_CENTER.y)
+}
+
+#[test]
+fn arrow_keys_only_move_the_focused_slider() {
+    let mut app = WidgetTestApp::new();
+    let first = spawn_slider(&mut app, |builder| builder);
+    let second = spawn_slider(&mut app, |builder| builder);
+
+    app.tap_key(KeyCode::Tab);
+    assert!(app.has::<WidgetFocused>(first));
+    app.tap_key(KeyCode::Tab);
+    assert!(app.has::<WidgetFocused>(second));
+    assert!(!app.has::<WidgetFocused>(first));
+
+    app.tap_key(KeyCode::ArrowRight);
+
+    assert_eq!(app.get::<Slider>(first).value, 50.0);
+    assert_eq!(app.get::<Slider>(second).value, 51.0);
+    let events = app.take_events::<SliderValueChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].entity, second);
+    assert_eq!(events[0].previous_value, 50.0);
+    assert_eq!(events[0].new_value, 51.0);
+}
+
+#[test]
+fn home_and_end_jump_to_the_limits() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder);
+    app.tap_key(KeyCode::Tab);
+
+    app.tap_key(KeyCode::End);
+    assert_eq!(app.get::<Slider>(slider).value, 100.0);
+
+    app.tap_key(KeyCode::Home);
+    assert_eq!(app.get::<Slider>(slider).value, 0.0);
+}
+
+#[test]
+fn arrow_keys_step_along_the_track_on_a_log_scale() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| {
+        builder.with_range(20.0, 20_000.0).with_scale(SliderScale::Logarithmic).with_value(15_000.0)
+    });
+    app.tap_key(KeyCode::Tab);
+
+    // One step's share of the track is worth several hertz up here...
+    app.tap_key(KeyCode::ArrowRight);
+    assert_eq!(app.get::<Slider>(slider).value, 15_005.0);
+
+    // ...and less than one down at the bottom, where the step itself wins
+    app.tap_key(KeyCode::Home);
+    app.tap_key(KeyCode::ArrowRight);
+    assert_eq!(app.get::<Slider>(slider).value, 21.0);
+}
+
+#[test]
+fn dragging_the_handle_follows_the_cursor() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder);
+    let handle = lay_out(&mut app, slider);
+
+    app.set_interaction(handle, Interaction::Pressed)
+        .press_mouse(MouseButton::Left)
+        .move_cursor(track_point(0.25))
+        .update();
+    assert_eq!(app.get::<Slider>(slider).value, 25.0);
+
+    app.move_cursor(track_point(1.5)).update();
+    assert_eq!(app.get::<Slider>(slider).value, 100.0);
+
+    let events = app.take_events::<SliderValueChangedEvent>();
+    assert_eq!(events.len(), 2);
+    assert!(events.iter().all(|event| !event.preview && event.kind == SliderChangeKind::User));
+}
+
+#[test]
+fn release_mode_commits_once_when_the_drag_ends() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder.with_emit_mode(SliderEmitMode::OnRelease));
+    let handle = lay_out(&mut app, slider);
+
+    app.set_interaction(handle, Interaction::Pressed)
+        .press_mouse(MouseButton::Left)
+        .move_cursor(track_point(0.25))
+        .update();
+    app.move_cursor(track_point(0.75)).update();
+    assert!(app.take_events::<SliderCommittedEvent>().is_empty());
+    assert!(app.take_events::<SliderValueChangedEvent>().iter().all(|event| event.preview));
+
+    app.release_mouse(MouseButton::Left).update();
+
+    let commits = app.take_events::<SliderCommittedEvent>();
+    assert_eq!(commits.len(), 1);
+    assert_eq!(commits[0].start_value, 50.0);
+    assert_eq!(commits[0].final_value, 75.0);
+}
+
+#[test]
+fn typed_value_is_parsed_and_applied() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder.with_value_input(true));
+    let input = app.entities::<With<SliderValueInput>>()[0];
+
+    app.click(input);
+    assert!(app.has::<SliderValueEditing>(input));
+
+    app.type_text("75").update();
+    app.tap_key(KeyCode::Enter);
+
+    assert_eq!(app.get::<Slider>(slider).value, 75.0);
+    assert!(!app.has::<SliderValueEditing>(input));
+}
+
+#[test]
+fn set_slider_value_steps_clamps_and_reports_programmatic_change() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder.with_emit_mode(SliderEmitMode::OnRelease));
+
+    app.run_commands(|commands| {
+        commands.entity(slider).set_slider_value(42.4);
+    });
+    app.update();
+    assert_eq!(app.get::<Slider>(slider).value, 42.0);
+
+    app.run_commands(|commands| {
+        commands.entity(slider).set_slider_value(500.0);
+    });
+    app.update();
+    assert_eq!(app.get::<Slider>(slider).value, 100.0);
+
+    let events = app.take_events::<SliderValueChangedEvent>();
+    assert_eq!(events.len(), 2);
+    assert!(events.iter().all(|event| event.kind == SliderChangeKind::Programmatic));
+    assert_eq!(app.take_events::<SliderCommittedEvent>().len(), 2);
+}
+
+#[test]
+fn silent_set_slider_value_sends_no_event() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder);
+
+    app.run_commands(|commands| {
+        commands.entity(slider).set_slider_value_silently(10.0);
+    });
+    app.update();
+
+    assert_eq!(app.get::<Slider>(slider).value, 10.0);
+    assert!(app.take_events::<SliderValueChangedEvent>().is_empty());
+}
+
+#[test]
+fn track_click_jumps_to_the_cursor() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder.with_track_click(SliderTrackClick::JumpToCursor));
+    let handle = lay_out(&mut app, slider);
+    let track = app.get::<ChildOf>(handle).parent();
+
+    app.set_interaction(track, Interaction::Pressed)
+        .press_mouse(MouseButton::Left)
+        .move_cursor(track_point(0.8))
+        .update();
+
+    assert_eq!(app.get::<Slider>(slider).value, 80.0);
+    assert_eq!(app.take_events::<SliderValueChangedEvent>().len(), 1);
+}
+
+#[test]
+fn track_click_pages_toward_the_cursor_without_passing_it() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder.with_track_click(SliderTrackClick::PageTowardCursor));
+    let handle = lay_out(&mut app, slider);
+    let track = app.get::<ChildOf>(handle).parent();
+
+    app.set_interaction(track, Interaction::Pressed)
+        .press_mouse(MouseButton::Left)
+        .move_cursor(track_point(0.55))
+        .update();
+
+    assert_eq!(app.get::<Slider>(slider).value, 55.0);
+}
+
+#[test]
+fn ignored_track_clicks_leave_the_value_alone() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder);
+    let handle = lay_out(&mut app, slider);
+    let track = app.get::<ChildOf>(handle).parent();
+
+    app.set_interaction(track, Interaction::Pressed)
+        .press_mouse(MouseButton::Left)
+        .move_cursor(track_point(0.8))
+        .update();
+
+    assert_eq!(app.get::<Slider>(slider).value, 50.0);
+}
+
+#[test]
+fn wheel_steps_the_hovered_slider() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder.with_mouse_wheel(SliderWheel::default()));
+    let handle = lay_out(&mut app, slider);
+    let track = app.get::<ChildOf>(handle).parent();
+
+    app.scroll(1.0).update();
+    assert_eq!(app.get::<Slider>(slider).value, 50.0);
+
+    app.set_interaction(track, Interaction::Hovered).scroll(1.0).update();
+    assert_eq!(app.get::<Slider>(slider).value, 51.0);
+
+    app.scroll(-2.0).update();
+    assert_eq!(app.get::<Slider>(slider).value, 50.0);
+}
+
+#[test]
+fn fine_wheel_moves_by_a_fraction_of_a_step_with_either_shift() {
+    let mut app = WidgetTestApp::new();
+    let slider = spawn_slider(&mut app, |builder| builder.with_mouse_wheel(SliderWheel::default()));
+    let handle = lay_out(&mut app, slider);
+    let track = app.get::<ChildOf>(handle).parent();
+    app.set_interaction(track, Interaction::Hovered);
+
+    app.press_key(KeyCode::ShiftRight).scroll(1.0).update();
+    assert!((app.get::<Slider>(slider).value - 50.1).abs() < 1e-4);
+
+    app.release_key(KeyCode::ShiftRight).press_key(KeyCode::ShiftLeft).scroll(1.0).update();
+    assert!((app.get::<Slider>(slider).value - 50.2).abs() < 1e-4);
+    assert_eq!(app.take_events::<SliderValueChangedEvent>().len(), 2);
+}
+
+fn spawn_range(
+    app: &mut WidgetTestApp,
+    build: impl for<'w, 's, 'a> FnOnce(RangeSliderBuilder<'w, 's, 'a>) -> RangeSliderBuilder<'w, 's, 'a>,
+) -> Entity {
+    app.spawn_with(|commands| build(RangeSliderBuilder::new(commands)).spawn())
+}
+
+#[test]
+fn range_builder_resolves_values_regardless_of_call_order() {
+    let mut app = WidgetTestApp::new();
+    let values_first = spawn_range(&mut app, |builder| builder.with_values(30.0, 70.0).with_range(0.0, 100.0));
+    let range_first = spawn_range(&mut app, |builder| builder.with_range(0.0, 100.0).with_values(70.0, 30.0));
+    let range_only = spawn_range(&mut app, |builder| builder.with_range(10.0, 20.0));
+
+    for range in [values_first, range_first] {
+        let range = app.get::<RangeSlider>(range);
+        assert_eq!((range.low, range.high), (30.0, 70.0));
+    }
+    let range = app.get::<RangeSlider>(range_only);
+    assert_eq!((range.low, range.high), (10.0, 20.0));
+}
+
+#[test]
+fn range_builder_applies_min_gap_to_the_initial_values() {
+    let mut app = WidgetTestApp::new();
+    let widened = spawn_range(&mut app, |builder| {
+        builder.with_values(40.0, 42.0).with_min_gap(10.0).with_range(0.0, 100.0)
+    });
+    let at_top = spawn_range(&mut app, |builder| {
+        builder.with_range(0.0, 100.0).with_min_gap(10.0).with_values(98.0, 100.0)
+    });
+
+    let range = app.get::<RangeSlider>(widened);
+    assert_eq!((range.low, range.high), (40.0, 50.0));
+    let range = app.get::<RangeSlider>(at_top);
+    assert_eq!((range.low, range.high), (90.0, 100.0));
+}
+
+#[test]
+fn arrow_keys_move_the_focused_range_thumb_within_the_gap() {
+    let mut app = WidgetTestApp::new();
+    app.record_events::<SliderRangeChangedEvent>();
+    let range = spawn_range(&mut app, |builder| {
+        builder.with_range(0.0, 100.0).with_values(40.0, 60.0).with_step(5.0).with_min_gap(10.0)
+    });
+    let (low_handle, high_handle) = {
+        let range = app.get::<RangeSlider>(range);
+        (range.low_handle, range.high_handle)
+    };
+
+    app.tap_key(KeyCode::Tab);
+    assert!(app.has::<WidgetFocused>(low_handle));
+    app.tap_key(KeyCode::ArrowRight);
+    app.tap_key(KeyCode::ArrowRight);
+    assert_eq!(app.get::<RangeSlider>(range).low, 50.0);
+
+    let events = app.take_events::<SliderRangeChangedEvent>();
+    assert_eq!(events.len(), 2);
+    assert_eq!((events[0].previous_low, events[0].new_low), (40.0, 45.0));
+
+    app.tap_key(KeyCode::Tab);
+    assert!(app.has::<WidgetFocused>(high_handle));
+    app.tap_key(KeyCode::Home);
+    app.tap_key(KeyCode::End);
+    let slider = app.get::<RangeSlider>(range);
+    assert_eq!((slider.low, slider.high), (50.0, 100.0));
+}
+
+#[test]
+fn dragging_stacked_range_thumbs_moves_the_one_that_can_follow() {
+    let mut app = WidgetTestApp::new();
+    let range = spawn_range(&mut app, |builder| builder.with_range(0.0, 100.0).with_values(50.0, 50.0));
+    let (low_handle, high_handle) = {
+        let range = app.get::<RangeSlider>(range);
+        (range.low_handle, range.high_handle)
+    };
+    let track = app.get::<ChildOf>(low_handle).parent();
+    app.set_layout(track, TRACK_CENTER, TRACK_SIZE);
+
+    // Both thumbs report the press, as overlapping nodes would
+    app.set_interaction(low_handle, Interaction::Pressed)
+        .set_interaction(high_handle, Interaction::Pressed)
+        .press_mouse(MouseButton::Left)
+        .move_cursor(track_point(0.75))
+        .update();
+    let slider = app.get::<RangeSlider>(range);
+    assert_eq!((slider.low, slider.high), (50.0, 75.0));
+
+    // Pushing the high thumb back onto the low one hands the drag over
+    app.set_interaction(low_handle, Interaction::None)
+        .move_cursor(track_point(0.5))
+        .update();
+    app.move_cursor(track_point(0.25)).update();
+    let slider = app.get::<RangeSlider>(range);
+    assert_eq!((slider.low, slider.high), (25.0, 50.0));
+}
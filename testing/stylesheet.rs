@@ -0,0 +1,65 @@
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::stylesheet::*;
+use crate::theme::WidgetTheme;
+
+fn parse(source: &str) -> Result<WidgetTheme, String> {
+    StyleSheetFile::parse(source.as_bytes())
+        .map(|file| file.into_theme(|_| Handle::default()))
+        .map_err(|error| error.to_string())
+}
+
+#[test]
+fn sheets_only_override_the_keys_they_list() {
+    let theme = parse(r##"(palette: (accent: "#ff0000", controls: (hover: "#00ff00")), radii: (control: 6))"##).unwrap();
+
+    let defaults = WidgetTheme::default();
+    assert_eq!(theme.palette.accent, Color::srgb(1.0, 0.0, 0.0));
+    assert_eq!(theme.palette.controls.hover, Color::srgb(0.0, 1.0, 0.0));
+    assert_eq!(theme.radii.control, 6.0);
+    assert_eq!(theme.palette.track, defaults.palette.track);
+    assert_eq!(theme.spacing, defaults.spacing);
+}
+
+#[test]
+fn unknown_keys_are_reported_with_their_position() {
+    let error = parse("(\n    palette: (acent: \"#ff0000\"),\n)").unwrap_err();
+
+    assert!(error.contains("line 2"), "{error}");
+    assert!(error.contains("acent"), "{error}");
+}
+
+#[test]
+fn bad_colors_are_reported() {
+    let error = parse(r##"(palette: (accent: "#ggg"))"##).unwrap_err();
+
+    assert!(error.contains("invalid color \"#ggg\""), "{error}");
+}
+
+#[test]
+fn active_sheet_drives_the_theme_and_reloads() {
+    let mut app = WidgetTestApp::with_plugins((AssetPlugin::default(), crate::WidgetsPlugin, WidgetStyleSheetPlugin));
+    let mut sheet_theme = WidgetTheme::default();
+    sheet_theme.palette.accent = Color::srgb(1.0, 0.0, 0.0);
+
+    let handle = app
+        .world()
+        .resource_mut::<Assets<WidgetStyleSheet>>()
+        .add(WidgetStyleSheet { theme: sheet_theme });
+    app.world().insert_resource(ActiveWidgetStyleSheet(handle.clone()));
+    app.update();
+    assert_eq!(app.world().resource::<WidgetTheme>().palette.accent, Color::srgb(1.0, 0.0, 0.0));
+
+    // Same path a hot reload takes
+    app.world()
+        .resource_mut::<Assets<WidgetStyleSheet>>()
+        .get_mut(&handle)
+        .unwrap()
+        .theme
+        .palette
+        .accent = Color::srgb(0.0, 1.0, 0.0);
+    app.step(2);
+    assert_eq!(app.world().resource::<WidgetTheme>().palette.accent, Color::srgb(0.0, 1.0, 0.0));
+}
@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::focus::{Focusable, WidgetFocus, WidgetFocused};
+use crate::tab_group::*;
+
+fn spawn_tabs(app: &mut WidgetTestApp) -> Entity {
+    let group = app.spawn_with(|commands| {
+        TabGroupBuilder::new(commands)
+            .with_tab("One", |commands| commands.spawn(Node::default()).id())
+            .with_tab("Two", |commands| commands.spawn(Node::default()).id())
+            .with_tab("Three", |commands| commands.spawn(Node::default()).id())
+            .spawn()
+    });
+    // Drop the initialization event
+    app.update();
+    app.take_events::<TabChangedEvent>();
+    group
+}
+
+fn button(app: &mut WidgetTestApp, group: Entity, index: usize) -> Entity {
+    app.get::<TabGroupMeta>(group).button_entities[index]
+}
+
+fn content_visibility(app: &mut WidgetTestApp, group: Entity) -> Vec<Visibility> {
+    let contents = app.get::<TabGroupMeta>(group).content_entities.clone();
+    contents.into_iter().map(|entity| *app.get::<Visibility>(entity)).collect()
+}
+
+#[test]
+fn pressing_tab_button_selects_tab() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_tabs(&mut app);
+    let second = button(&mut app, group, 1);
+
+    app.click(second);
+
+    assert_eq!(app.get::<TabGroup>(group).selected_tab, 1);
+    let events = app.take_events::<TabChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].group_entity, group);
+    assert_eq!(events[0].previous_tab, 0);
+    assert_eq!(events[0].new_tab, 1);
+    assert_eq!(events[0].change_kind, TabChangeKind::UserInteraction);
+}
+
+#[test]
+fn selected_tab_content_is_the_only_visible_one() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_tabs(&mut app);
+    let third = button(&mut app, group, 2);
+
+    app.click(third).update();
+
+    assert_eq!(
+        content_visibility(&mut app, group),
+        vec![Visibility::Hidden, Visibility::Hidden, Visibility::Visible]
+    );
+}
+
+#[test]
+fn pressing_selected_tab_is_not_a_change() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_tabs(&mut app);
+    let first = button(&mut app, group, 0);
+
+    app.click(first);
+
+    assert!(app.take_events::<TabChangedEvent>().is_empty());
+}
+
+#[test]
+fn arrow_keys_wrap_from_focused_tab() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_tabs(&mut app);
+    let first = button(&mut app, group, 0);
+    app.world().resource_mut::<WidgetFocus>().set(first);
+
+    app.tap_key(KeyCode::ArrowLeft);
+
+    assert_eq!(app.get::<TabGroup>(group).selected_tab, 2);
+    let events = app.take_events::<TabChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].new_tab, 2);
+}
+
+#[test]
+fn only_the_selected_tab_is_a_tab_stop() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_tabs(&mut app);
+    let (first, third) = (button(&mut app, group, 0), button(&mut app, group, 2));
+
+    app.tap_key(KeyCode::Tab);
+    assert!(app.has::<WidgetFocused>(first));
+    app.tap_key(KeyCode::Tab);
+    assert!(app.has::<WidgetFocused>(first));
+
+    app.tap_key(KeyCode::ArrowLeft);
+    assert!(app.has::<WidgetFocused>(third));
+    assert_eq!(app.get::<Focusable>(first).tab_index, -1);
+    assert_eq!(app.get::<Focusable>(third).tab_index, 0);
+}
+
+#[test]
+fn spawning_reports_the_initial_tab() {
+    let mut app = WidgetTestApp::new();
+    let group = app.spawn_with(|commands| {
+        TabGroupBuilder::new(commands)
+            .with_tab("One", |commands| commands.spawn(Node::default()).id())
+            .with_tab("Two", |commands| commands.spawn(Node::default()).id())
+            .with_initial_tab(1)
+            .spawn()
+    });
+    app.update();
+
+    let events = app.take_events::<TabChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].group_entity, group);
+    assert_eq!(events[0].new_tab, 1);
+    assert_eq!(events[0].change_kind, TabChangeKind::Initialization);
+}
+
+#[test]
+fn select_tab_swaps_content_and_reports_programmatic_change() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_tabs(&mut app);
+
+    app.run_commands(|commands| {
+        commands.entity(group).select_tab(1);
+    });
+    app.update();
+
+    assert_eq!(app.get::<TabGroup>(group).selected_tab, 1);
+    assert_eq!(
+        content_visibility(&mut app, group),
+        vec![Visibility::Hidden, Visibility::Visible, Visibility::Hidden]
+    );
+    let events = app.take_events::<TabChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].change_kind, TabChangeKind::Programmatic);
+}
+
+#[test]
+fn silent_select_updates_visuals_without_an_event() {
+    let mut app = WidgetTestApp::new();
+    let group = spawn_tabs(&mut app);
+    let third = button(&mut app, group, 2);
+
+    app.run_commands(|commands| {
+        commands.entity(group).select_tab_silently(2);
+    });
+    app.update();
+
+    assert_eq!(
+        content_visibility(&mut app, group),
+        vec![Visibility::Hidden, Visibility::Hidden, Visibility::Visible]
+    );
+    assert!(app.has::<TabActive>(third));
+    assert!(app.take_events::<TabChangedEvent>().is_empty());
+}
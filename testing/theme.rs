@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::dropdown::*;
+use crate::progress_bar::*;
+use crate::slider::{components::*, *};
+use crate::tab_group::*;
+use crate::theme::*;
+use crate::toggle::*;
+
+const RED: Color = Color::srgb(1.0, 0.0, 0.0);
+const GREEN: Color = Color::srgb(0.0, 1.0, 0.0);
+
+fn fill_color(app: &mut WidgetTestApp, bar: Entity) -> Color {
+    let fill = app.get::<ProgressBarParts>(bar).fill;
+    app.get::<BackgroundColor>(fill).0
+}
+
+#[test]
+fn widgets_spawn_with_the_global_theme() {
+    let mut app = WidgetTestApp::new();
+    app.world().resource_mut::<WidgetTheme>().palette.accent = RED;
+
+    let bar = app.spawn_with(|commands| ProgressBarBuilder::new(commands).spawn());
+    app.update();
+
+    assert_eq!(fill_color(&mut app, bar), RED);
+    assert_eq!(app.get::<ProgressBarVisuals>(bar).fill_color, RED);
+}
+
+#[test]
+fn changing_the_theme_restyles_spawned_widgets() {
+    let mut app = WidgetTestApp::new();
+    let bar = app.spawn_with(|commands| ProgressBarBuilder::new(commands).spawn());
+    app.update();
+
+    app.world().resource_mut::<WidgetTheme>().palette.accent = GREEN;
+    app.update();
+
+    assert_eq!(fill_color(&mut app, bar), GREEN);
+}
+
+#[test]
+fn overrides_win_over_the_global_theme() {
+    let mut app = WidgetTestApp::new();
+    let mut theme = WidgetTheme::default();
+    theme.palette.accent = RED;
+
+    let themed = app.spawn_with(|commands| ProgressBarBuilder::new(commands).with_theme(theme).spawn());
+    let plain = app.spawn_with(|commands| ProgressBarBuilder::new(commands).spawn());
+    app.update();
+    app.world().resource_mut::<WidgetTheme>().palette.accent = GREEN;
+    app.update();
+
+    assert_eq!(fill_color(&mut app, themed), RED);
+    assert_eq!(fill_color(&mut app, plain), GREEN);
+}
+
+#[test]
+fn selected_tab_uses_the_selected_state_color() {
+    let mut app = WidgetTestApp::new();
+    app.world().resource_mut::<WidgetTheme>().palette.controls.selected = RED;
+
+    let group = app.spawn_with(|commands| {
+        TabGroupBuilder::new(commands)
+            .with_tab("A", |commands| commands.spawn(Node::default()).id())
+            .with_tab("B", |commands| commands.spawn(Node::default()).id())
+            .spawn()
+    });
+    app.update();
+
+    let buttons = app.get::<TabGroupMeta>(group).button_entities.clone();
+    assert_eq!(app.get::<BackgroundColor>(buttons[0]).0, RED);
+    assert_ne!(app.get::<BackgroundColor>(buttons[1]).0, RED);
+}
+
+#[test]
+fn default_toggles_keep_their_green_on_color() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+    app.update();
+
+    assert_eq!(app.get::<ToggleConfig>(toggle).on_color, ToggleConfig::default().on_color);
+}
+
+#[test]
+fn changing_the_theme_restyles_toggles_but_not_their_given_colors() {
+    let mut app = WidgetTestApp::new();
+    let plain = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+    let configured = app.spawn_with(|commands| {
+        ToggleBuilder::new(commands)
+            .with_config(ToggleConfig {
+                on_color: RED,
+                ..default()
+            })
+            .spawn()
+    });
+    app.update();
+
+    {
+        let mut theme = app.world().resource_mut::<WidgetTheme>();
+        theme.palette.success = GREEN;
+        theme.palette.track = RED;
+    }
+    app.update();
+
+    assert_eq!(app.get::<ToggleConfig>(plain).on_color, GREEN);
+    assert_eq!(app.get::<ToggleConfig>(configured).on_color, RED);
+    assert_eq!(app.get::<ToggleConfig>(configured).off_color, RED);
+}
+
+#[test]
+fn given_progress_bar_colors_survive_restyling() {
+    let mut app = WidgetTestApp::new();
+    let bar = app.spawn_with(|commands| {
+        ProgressBarBuilder::new(commands)
+            .with_visuals(ProgressBarVisuals {
+                fill_color: RED,
+                ..default()
+            })
+            .spawn()
+    });
+    app.update();
+    app.world().resource_mut::<WidgetTheme>().palette.accent = GREEN;
+    app.update();
+
+    assert_eq!(fill_color(&mut app, bar), RED);
+}
+
+#[test]
+fn changing_the_theme_restyles_sliders() {
+    let mut app = WidgetTestApp::new();
+    let slider = app.spawn_with(|commands| SliderBuilder::new(commands).spawn());
+    let handle = app.get::<Slider>(slider).handle_entity;
+    let fill = app.entities::<With<SliderFill>>()[0];
+    app.update();
+
+    {
+        let mut theme = app.world().resource_mut::<WidgetTheme>();
+        theme.palette.accent = RED;
+        theme.palette.handle = GREEN;
+    }
+    app.update();
+
+    assert_eq!(app.get::<BackgroundColor>(fill).0, RED);
+    assert_eq!(app.get::<BackgroundColor>(handle).0, GREEN);
+}
+
+#[test]
+fn changing_the_theme_restyles_dropdowns() {
+    let mut app = WidgetTestApp::new();
+    let dropdown = app.spawn_dropdown(DropdownBuilder::new().with_option("Red", None).build());
+    let button = app.entities::<With<DropdownButton>>()[0];
+    app.update();
+
+    {
+        let mut theme = app.world().resource_mut::<WidgetTheme>();
+        theme.palette.surface = RED;
+        theme.palette.control_text = GREEN;
+        theme.typography.body_size = 20.0;
+    }
+    app.update();
+
+    assert_eq!(app.get::<BackgroundColor>(dropdown).0, RED);
+    assert_eq!(app.get::<TextColor>(button).0, GREEN);
+    assert_eq!(app.get::<TextFont>(button).font_size, 20.0);
+}
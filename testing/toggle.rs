@@ -0,0 +1,229 @@
+use bevy::prelude::*;
+
+use super::WidgetTestApp;
+use crate::anim::{EasingFunction, Transition};
+use crate::focus::WidgetFocused;
+use crate::toggle::*;
+
+#[test]
+fn click_flips_toggle_and_reports_user_change() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+
+    app.click(toggle);
+
+    assert!(app.get::<Toggle>(toggle).is_on());
+    let events = app.take_events::<ToggleChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].toggle_entity, toggle);
+    assert_eq!(events[0].previous_state, ToggleState::Off);
+    assert_eq!(events[0].new_state, ToggleState::On);
+    assert_eq!(events[0].kind, ToggleChangeKind::User);
+}
+
+#[test]
+fn disabled_toggle_ignores_clicks() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).disabled(true).spawn());
+
+    app.click(toggle);
+
+    assert!(!app.get::<Toggle>(toggle).is_on());
+    assert!(app.take_events::<ToggleChangedEvent>().is_empty());
+}
+
+#[test]
+fn space_flips_focused_toggle_only() {
+    let mut app = WidgetTestApp::new();
+    let focused = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+    let other = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+
+    app.click(focused);
+    app.take_events::<ToggleChangedEvent>();
+    assert!(app.has::<WidgetFocused>(focused));
+
+    app.tap_key(KeyCode::Space);
+
+    assert!(!app.get::<Toggle>(focused).is_on());
+    assert!(!app.get::<Toggle>(other).is_on());
+    let events = app.take_events::<ToggleChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].toggle_entity, focused);
+}
+
+#[test]
+fn knob_animates_towards_new_state() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+
+    app.click(toggle);
+    app.step(60);
+
+    assert_eq!(app.get::<ToggleAnimation>(toggle).progress.value(), 1.0);
+}
+
+#[test]
+fn knob_and_color_follow_their_own_transitions() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| {
+        ToggleBuilder::new(commands)
+            .with_config(ToggleConfig {
+                knob_transition: Transition::tween(1.0, EasingFunction::Linear),
+                color_transition: Transition::tween(0.05, EasingFunction::Linear),
+                ..default()
+            })
+            .spawn()
+    });
+
+    app.click(toggle);
+    app.step(6);
+
+    let animation = app.get::<ToggleAnimation>(toggle);
+    assert_eq!(animation.color.value(), 1.0);
+    assert!(animation.progress.value() < 1.0);
+}
+
+#[test]
+fn set_toggle_reports_programmatic_change() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+
+    app.run_commands(|commands| {
+        commands.entity(toggle).set_toggle(true);
+    });
+    app.update();
+
+    assert!(app.get::<Toggle>(toggle).is_on());
+    assert!(!app.has::<ToggleNeedsVisualUpdate>(toggle));
+    let events = app.take_events::<ToggleChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind, ToggleChangeKind::Programmatic);
+}
+
+#[test]
+fn silent_set_toggle_sends_no_event() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+
+    app.run_commands(|commands| {
+        commands.entity(toggle).set_toggle_silently(true);
+    });
+    app.update();
+
+    assert!(app.get::<Toggle>(toggle).is_on());
+    assert!(app.take_events::<ToggleChangedEvent>().is_empty());
+}
+
+/// Presses a default 50x25 toggle at x = 100 and drags the cursor by `offset`.
+fn press_and_drag(app: &mut WidgetTestApp, toggle: Entity, offset: f32) {
+    app.move_cursor(Vec2::new(100.0, 10.0))
+        .set_interaction(toggle, Interaction::Pressed)
+        .press_mouse(MouseButton::Left)
+        .update();
+    app.move_cursor(Vec2::new(100.0 + offset, 10.0)).update();
+}
+
+fn release(app: &mut WidgetTestApp, toggle: Entity) {
+    app.set_interaction(toggle, Interaction::Hovered)
+        .release_mouse(MouseButton::Left)
+        .update();
+}
+
+#[test]
+fn dragging_moves_the_knob_and_switches_on_release() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+
+    // 25px of knob travel, so 15px is 60% of the way
+    press_and_drag(&mut app, toggle, 15.0);
+
+    assert!((app.get::<ToggleAnimation>(toggle).progress.value() - 0.6).abs() < 1e-4);
+    assert!(!app.get::<Toggle>(toggle).is_on());
+    assert!(app.take_events::<ToggleChangedEvent>().is_empty());
+
+    release(&mut app, toggle);
+
+    assert!(app.get::<Toggle>(toggle).is_on());
+    let events = app.take_events::<ToggleChangedEvent>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].new_state, ToggleState::On);
+    assert!(!app.has::<ToggleDragState>(toggle));
+    app.step(60);
+    assert_eq!(app.get::<ToggleAnimation>(toggle).progress.value(), 1.0);
+}
+
+#[test]
+fn dragging_back_to_the_same_side_sends_no_event() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+
+    press_and_drag(&mut app, toggle, 8.0);
+    release(&mut app, toggle);
+
+    assert!(!app.get::<Toggle>(toggle).is_on());
+    assert!(app.take_events::<ToggleChangedEvent>().is_empty());
+    app.step(60);
+    assert_eq!(app.get::<ToggleAnimation>(toggle).progress.value(), 0.0);
+}
+
+#[test]
+fn movement_under_the_drag_threshold_is_a_click() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| ToggleBuilder::new(commands).spawn());
+
+    press_and_drag(&mut app, toggle, 2.0);
+    assert_eq!(app.get::<ToggleAnimation>(toggle).progress.value(), 0.0);
+    release(&mut app, toggle);
+
+    assert!(app.get::<Toggle>(toggle).is_on());
+    assert_eq!(app.take_events::<ToggleChangedEvent>().len(), 1);
+}
+
+fn content_alpha(app: &mut WidgetTestApp, toggle: Entity) -> Vec<f32> {
+    let content = app.get::<ToggleParts>(toggle).content.clone();
+    content
+        .into_iter()
+        .map(|entity| match app.world().get::<TextColor>(entity) {
+            Some(color) => color.0.alpha(),
+            None => app.get::<ImageNode>(entity).color.alpha(),
+        })
+        .collect()
+}
+
+#[test]
+fn track_text_cross_fades_with_the_knob() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| {
+        ToggleBuilder::new(commands).with_track_text("ON", "OFF").spawn()
+    });
+    app.update();
+    assert_eq!(content_alpha(&mut app, toggle), [0.0, 1.0]);
+
+    app.click(toggle);
+    let fading = content_alpha(&mut app, toggle);
+    app.step(60);
+
+    assert!(fading[0] > 0.0 && fading[0] < 1.0);
+    assert!((fading[0] + fading[1] - 1.0).abs() < 1e-4);
+    assert_eq!(content_alpha(&mut app, toggle), [1.0, 0.0]);
+}
+
+#[test]
+fn knob_icons_sit_in_the_knob_and_dim_when_disabled() {
+    let mut app = WidgetTestApp::new();
+    let toggle = app.spawn_with(|commands| {
+        ToggleBuilder::new(commands)
+            .with_knob_icons(Handle::default(), Handle::default())
+            .with_initial_state(true)
+            .disabled(true)
+            .spawn()
+    });
+    app.update();
+
+    let parts = app.get::<ToggleParts>(toggle).clone();
+    for &icon in &parts.content {
+        let slot = app.get::<ChildOf>(icon).parent();
+        assert_eq!(app.get::<ChildOf>(slot).parent(), parts.knob);
+    }
+    assert_eq!(content_alpha(&mut app, toggle), [0.5, 0.0]);
+}
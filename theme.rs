@@ -0,0 +1,276 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Look and feel shared by every widget.
+///
+/// Builders spawn with the theme applied, and editing the resource restyles
+/// widgets that already exist. A `WidgetThemeOverride` on a widget, or on any
+/// of its ancestors, takes precedence for that subtree.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct WidgetTheme {
+    pub palette: ThemePalette,
+    pub typography: ThemeTypography,
+    pub radii: ThemeRadii,
+    pub spacing: ThemeSpacing,
+    pub focus_ring: FocusRingStyle,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemePalette {
+    /// Widget bodies, like the dropdown frame and knob dial
+    pub surface: Color,
+    /// Empty part of slider, toggle, knob and progress tracks
+    pub track: Color,
+    /// Filled part of tracks, and checked checkboxes and radio buttons
+    pub accent: Color,
+    /// Tracks of switched-on toggles
+    pub success: Color,
+    /// Slider handles, toggle knobs and knob indicators
+    pub handle: Color,
+    pub border: Color,
+    pub text: Color,
+    /// Text drawn on `controls` backgrounds
+    pub control_text: Color,
+    pub error: Color,
+    /// Buttons, tabs and dropdown options
+    pub controls: StateColors,
+}
+
+impl Default for ThemePalette {
+    fn default() -> Self {
+        Self {
+            surface: Color::srgba(0.1, 0.1, 0.1, 0.8),
+            track: Color::srgb(0.5, 0.5, 0.5),
+            accent: Color::srgb(0.2, 0.5, 0.9),
+            success: Color::srgb(0.2, 0.8, 0.2),
+            handle: Color::WHITE,
+            border: Color::srgb(0.3, 0.3, 0.3),
+            text: Color::WHITE,
+            control_text: Color::srgb(0.1, 0.1, 0.1),
+            error: Color::srgb(0.9, 0.2, 0.2),
+            controls: StateColors::default(),
+        }
+    }
+}
+
+/// Background colors for the states an interactive element can be in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateColors {
+    pub normal: Color,
+    pub hover: Color,
+    pub pressed: Color,
+    pub focused: Color,
+    pub disabled: Color,
+    pub selected: Color,
+}
+
+impl Default for StateColors {
+    fn default() -> Self {
+        Self {
+            normal: Color::srgb(0.65, 0.65, 0.65),
+            hover: Color::srgb(0.75, 0.75, 0.75),
+            pressed: Color::srgb(0.6, 0.6, 0.6),
+            focused: Color::srgb(0.8, 0.8, 0.9),
+            disabled: Color::srgb(0.4, 0.4, 0.4),
+            selected: Color::srgb(0.9, 0.9, 0.9),
+        }
+    }
+}
+
+impl StateColors {
+    /// Color for the most important active state, in the order
+    /// disabled, selected, pressed, focused, hovered.
+    pub fn pick(&self, state: WidgetVisualState) -> Color {
+        if state.disabled {
+            self.disabled
+        } else if state.selected {
+            self.selected
+        } else if state.pressed {
+            self.pressed
+        } else if state.focused {
+            self.focused
+        } else if state.hovered {
+            self.hover
+        } else {
+            self.normal
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WidgetVisualState {
+    pub hovered: bool,
+    pub pressed: bool,
+    pub focused: bool,
+    pub disabled: bool,
+    pub selected: bool,
+}
+
+impl WidgetVisualState {
+    pub fn from_interaction(interaction: Interaction) -> Self {
+        Self {
+            hovered: interaction == Interaction::Hovered,
+            pressed: interaction == Interaction::Pressed,
+            ..default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeTypography {
+    pub font: Handle<Font>,
+    /// Buttons, tabs and dropdown text
+    pub body_size: f32,
+    /// Tick labels and dropdown options
+    pub label_size: f32,
+    /// Slider and progress readouts
+    pub value_size: f32,
+}
+
+impl Default for ThemeTypography {
+    fn default() -> Self {
+        Self {
+            font: Handle::default(),
+            body_size: 14.0,
+            label_size: 12.0,
+            value_size: 16.0,
+        }
+    }
+}
+
+impl ThemeTypography {
+    pub fn text_font(&self, font_size: f32) -> TextFont {
+        TextFont {
+            font: self.font.clone(),
+            font_size,
+            ..default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeRadii {
+    /// Buttons, dropdowns and fields
+    pub control: f32,
+    /// Toggle tracks
+    pub pill: f32,
+}
+
+impl Default for ThemeRadii {
+    fn default() -> Self {
+        Self {
+            control: 4.0,
+            pill: 12.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeSpacing {
+    /// Gap between neighbouring controls, like tab buttons
+    pub gap: f32,
+    /// Padding inside buttons and tabs
+    pub padding: f32,
+    /// Padding inside list items
+    pub item_padding: f32,
+}
+
+impl Default for ThemeSpacing {
+    fn default() -> Self {
+        Self {
+            gap: 4.0,
+            padding: 8.0,
+            item_padding: 10.0,
+        }
+    }
+}
+
+/// Outline drawn around the focused widget after keyboard navigation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusRingStyle {
+    pub color: Color,
+    pub width: f32,
+    /// Gap between the widget's edge and the inside of the ring
+    pub offset: f32,
+    pub radius: f32,
+}
+
+impl Default for FocusRingStyle {
+    fn default() -> Self {
+        Self {
+            color: Color::srgb(0.3, 0.6, 1.0),
+            width: 2.0,
+            offset: 2.0,
+            radius: 6.0,
+        }
+    }
+}
+
+/// Replaces the global theme for a widget and everything below it.
+#[derive(Component, Debug, Clone)]
+pub struct WidgetThemeOverride(pub WidgetTheme);
+
+/// Which of a widget's colors its builder was given, through `with_config`,
+/// `with_style` or `with_visuals`. Theme systems leave those colors alone.
+///
+/// A color counts as given when it differs from the widget's default, so
+/// passing a config with only its layout changed keeps the theme's colors.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExplicitColors(u32);
+
+impl ExplicitColors {
+    /// Flags each `(given, default)` pair that differs. Indices follow the
+    /// order of `pairs`, which is also the order theme systems check them in.
+    pub fn from_defaults(pairs: &[(Color, Color)]) -> Self {
+        let bits = pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (given, default))| given != default)
+            .fold(0, |bits, (index, _)| bits | 1 << index);
+        Self(bits)
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.0 & (1 << index) != 0
+    }
+
+    /// Writes `themed` into `color` unless color `index` was given.
+    pub fn apply(&self, index: usize, color: &mut Color, themed: Color) {
+        if !self.contains(index) {
+            *color = themed;
+        }
+    }
+}
+
+/// Resolves the theme that applies to an entity.
+#[derive(SystemParam)]
+pub struct ThemeLookup<'w, 's> {
+    theme: Res<'w, WidgetTheme>,
+    parents: Query<'w, 's, &'static ChildOf>,
+    overrides: Query<'w, 's, &'static WidgetThemeOverride>,
+    changed_overrides: Query<'w, 's, (), Changed<WidgetThemeOverride>>,
+}
+
+impl ThemeLookup<'_, '_> {
+    /// Whether already styled widgets need restyling this frame.
+    pub fn changed(&self) -> bool {
+        self.theme.is_changed() || !self.changed_overrides.is_empty()
+    }
+
+    /// The nearest override on `entity` or its ancestors, else the global theme.
+    pub fn get(&self, entity: Entity) -> &WidgetTheme {
+        std::iter::once(entity)
+            .chain(self.parents.iter_ancestors(entity))
+            .find_map(|entity| self.overrides.get(entity).ok())
+            .map_or(&*self.theme, |theme_override| &theme_override.0)
+    }
+}
+
+/// Adds the `WidgetTheme` resource.
+pub struct WidgetThemePlugin;
+
+impl Plugin for WidgetThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WidgetTheme>();
+    }
+}
@@ -1,28 +1,84 @@
 use bevy::prelude::*;
+use bevy::ecs::component::Mutable;
+use crate::binding::{Lens, WidgetBinding};
+use crate::focus::Focusable;
+use crate::label::{LabelPosition, LabelText};
+use crate::theme::{WidgetTheme, WidgetThemeOverride};
 use super::components::*;
+use std::marker::PhantomData;
 
-pub struct ToggleBuilder<'w, 's, 'a> {
+/// Builds a `Toggle`. `K` picks how it is drawn; use the `ToggleBuilder` and
+/// `CheckboxBuilder` aliases rather than naming it directly.
+pub struct ToggleWidgetBuilder<'w, 's, 'a, K: ToggleVisual> {
     commands: &'a mut Commands<'w, 's>,
-    initial_state: bool,
+    initial_state: ToggleState,
     config: ToggleConfig,
     disabled: bool,
+    binding: Option<WidgetBinding>,
+    theme: Option<WidgetTheme>,
+    focusable: Focusable,
+    label_text: LabelText,
+    visual: PhantomData<K>,
 }
 
-impl<'w, 's, 'a> ToggleBuilder<'w, 's, 'a> {
+/// Spawns a switch: a `Toggle` drawn as a track with a sliding knob.
+pub type ToggleBuilder<'w, 's, 'a> = ToggleWidgetBuilder<'w, 's, 'a, SwitchVisual>;
+
+/// Spawns a checkbox: a `Toggle` drawn as a box with a checkmark when on and a
+/// dash when mixed. It shares the toggle's events, commands and bindings.
+///
+/// ```ignore
+/// CheckboxBuilder::new(&mut commands)
+///     .with_initial_state(ToggleState::Mixed)
+///     .spawn();
+/// ```
+pub type CheckboxBuilder<'w, 's, 'a> = ToggleWidgetBuilder<'w, 's, 'a, CheckboxVisual>;
+
+/// How a `ToggleWidgetBuilder` draws its toggle.
+pub trait ToggleVisual {
+    fn default_config() -> ToggleConfig;
+
+    /// Spawns the parts under `toggle_entity` and inserts its node and parts.
+    fn spawn_visual(
+        commands: &mut Commands,
+        toggle_entity: Entity,
+        config: &ToggleConfig,
+        state: ToggleState,
+    );
+}
+
+pub struct SwitchVisual;
+
+pub struct CheckboxVisual;
+
+impl<'w, 's, 'a, K: ToggleVisual> ToggleWidgetBuilder<'w, 's, 'a, K> {
     pub fn new(commands: &'a mut Commands<'w, 's>) -> Self {
         Self {
             commands,
-            initial_state: false,
-            config: ToggleConfig::default(),
+            initial_state: ToggleState::Off,
+            config: K::default_config(),
             disabled: false,
+            binding: None,
+            theme: None,
+            focusable: Focusable::default(),
+            label_text: LabelText::default(),
+            visual: PhantomData,
         }
     }
 
-    pub fn with_initial_state(mut self, state: bool) -> Self {
-        self.initial_state = state;
+    /// Takes a `bool` or a `ToggleState`.
+    pub fn with_initial_state(mut self, state: impl Into<ToggleState>) -> Self {
+        self.initial_state = state.into();
+        self
+    }
+
+    /// Lets clicks reach `ToggleState::Mixed` with `ToggleCycle::TriState`.
+    pub fn with_cycle(mut self, cycle: ToggleCycle) -> Self {
+        self.config.cycle = cycle;
         self
     }
 
+    /// Colors in `config` that differ from the defaults win over the theme.
     pub fn with_config(mut self, config: ToggleConfig) -> Self {
         self.config = config;
         self
@@ -33,157 +89,354 @@ impl<'w, 's, 'a> ToggleBuilder<'w, 's, 'a> {
         self
     }
 
+    /// Position in Tab order; see `Focusable`.
+    pub fn with_tab_index(mut self, tab_index: i32) -> Self {
+        self.focusable = Focusable::with_tab_index(tab_index);
+        self
+    }
+
+    /// Styles this toggle with `theme` instead of the global `WidgetTheme`.
+    pub fn with_theme(mut self, theme: WidgetTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Shows `text` beside the toggle. Clicking it flips the toggle too.
+    /// Place the labelled toggle with `spawn_with_parent`.
+    pub fn with_label(mut self, text: impl Into<String>, position: LabelPosition) -> Self {
+        self.label_text.set_label(text, position);
+        self
+    }
+
+    /// Shows `text` in smaller type under the label.
+    pub fn with_description(mut self, text: impl Into<String>) -> Self {
+        self.label_text.set_description(text);
+        self
+    }
+
+    /// Keeps the toggle and a field of resource `R` in sync both ways.
+    pub fn bind_to<R: Resource>(mut self, lens: Lens<R, bool>) -> Self {
+        self.binding = Some(WidgetBinding::resource::<Toggle, R>(lens));
+        self
+    }
+
+    /// Keeps the toggle and a field of component `C` on `target` in sync both ways.
+    pub fn bind_to_component<C: Component<Mutability = Mutable>>(
+        mut self,
+        target: Entity,
+        lens: Lens<C, bool>,
+    ) -> Self {
+        self.binding = Some(WidgetBinding::component::<Toggle, C>(target, lens));
+        self
+    }
+
+    /// Returns the toggle. With a label or description the toggle sits
+    /// inside its label container; place it with `spawn_with_parent`.
     pub fn spawn(self) -> Entity {
-        // Spawn track
-        let track_entity = self.commands.spawn((
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                ..default()
-            },
-            BorderRadius::all(Val::Px(12.0)),
-            BackgroundColor(if self.initial_state {
-                self.config.on_color
-            } else {
-                self.config.off_color
-            }),
-            ToggleTrack,
-        )).id();
+        self.spawn_internal().1
+    }
 
-        // Spawn knob
-        let knob_x = if self.initial_state {
-            self.config.size.x - self.config.size.y + self.config.knob_margin
-        } else {
-            self.config.knob_margin
-        };
-        
-        let knob_entity = self.commands.spawn((
-            Node {
-                width: Val::Px(self.config.size.y - self.config.knob_margin * 2.0),
-                height: Val::Px(self.config.size.y - self.config.knob_margin * 2.0),
-                position_type: PositionType::Absolute,
-                left: Val::Px(knob_x),
-                ..default()
-            },
-            BorderRadius::all(Val::Percent(50.0)),
-            BackgroundColor(self.config.knob_color),
-            ToggleKnob,
-        )).id();
+    /// Adds the toggle, or its label container if it has a label, to
+    /// `parent_entity`.
+    pub fn spawn_with_parent(self, parent_entity: Entity) -> Entity {
+        let (commands, toggle_entity, root_entity) = self.spawn_internal();
+        commands.entity(parent_entity).add_children(&[root_entity]);
+        toggle_entity
+    }
 
-        let toggle_entity = self.commands.spawn((
+    pub fn spawn_state_scoped<T: States>(self, state: T) -> Entity {
+        #[cfg(debug_assertions)]
+        info!("Creating state-scoped toggle widget for state: {:?}", std::any::type_name::<T>());
+
+        let (commands, toggle_entity, root_entity) = self.spawn_internal();
+        commands.entity(toggle_entity).insert(StateScoped(state.clone()));
+        if root_entity != toggle_entity {
+            commands.entity(root_entity).insert(StateScoped(state));
+        }
+
+        #[cfg(debug_assertions)]
+        info!("State-scoped toggle widget created with entity: {:?}", toggle_entity);
+
+        toggle_entity
+    }
+
+    /// Returns the toggle and the entity to place in the layout.
+    fn spawn_internal(self) -> (&'a mut Commands<'w, 's>, Entity, Entity) {
+        let toggle_entity = self.commands.spawn_empty().id();
+        K::spawn_visual(self.commands, toggle_entity, &self.config, self.initial_state);
+
+        self.commands.entity(toggle_entity).insert((
             Button,
-            Node {
-                width: Val::Px(self.config.size.x),
-                height: Val::Px(self.config.size.y),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            BackgroundColor(Color::NONE),
             Toggle {
-                is_on: self.initial_state,
+                state: self.initial_state,
             },
+            self.config.explicit_colors(),
             self.config,
-            ToggleAnimation {
-                progress: if self.initial_state { 1.0 } else { 0.0 },
-                target_progress: if self.initial_state { 1.0 } else { 0.0 },
-                ..default()
-            },
-            ToggleParts {
-                track: track_entity,
-                knob: knob_entity,
-            },
+            ToggleAnimation::new(self.initial_state.progress()),
             ToggleNeedsVisualUpdate,
-        )).id();
+            self.focusable,
+        ));
 
         if self.disabled {
             self.commands.entity(toggle_entity).insert(ToggleDisabled);
         }
 
-        self.commands.entity(toggle_entity)
-            .add_children(&[track_entity, knob_entity]);
+        if let Some(binding) = self.binding {
+            self.commands.entity(toggle_entity).insert(binding);
+        }
 
-        toggle_entity
+        if let Some(theme) = self.theme {
+            self.commands.entity(toggle_entity).insert(WidgetThemeOverride(theme));
+        }
+
+        let root_entity = self.label_text.spawn(self.commands, toggle_entity).unwrap_or(toggle_entity);
+
+        (self.commands, toggle_entity, root_entity)
     }
+}
 
-    pub fn spawn_state_scoped<T: States>(self, state: T) -> Entity {
-        #[cfg(debug_assertions)]
-        info!("Creating state-scoped toggle widget for state: {:?}", std::any::type_name::<T>());
-        
-        // Spawn all components in one go, including StateScoped
-        // First spawn track
-        let track_entity = self.commands.spawn((
+impl<'w, 's, 'a> ToggleBuilder<'w, 's, 'a> {
+    /// Writes `on` and `off` in the track, beside the knob.
+    pub fn with_track_text(mut self, on: impl Into<String>, off: impl Into<String>) -> Self {
+        self.config.track_content = ToggleStateContent::new(on.into(), off.into());
+        self
+    }
+
+    /// Shows `on` and `off` in the track, beside the knob.
+    pub fn with_track_icons(mut self, on: Handle<Image>, off: Handle<Image>) -> Self {
+        self.config.track_content = ToggleStateContent::new(on, off);
+        self
+    }
+
+    /// Shows `on` and `off` in the knob, e.g. a sun and a moon.
+    pub fn with_knob_icons(mut self, on: Handle<Image>, off: Handle<Image>) -> Self {
+        self.config.knob_content = ToggleStateContent::new(on, off);
+        self
+    }
+}
+
+impl<'w, 's, 'a> CheckboxBuilder<'w, 's, 'a> {
+    /// Sets the box's width and height.
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.config.size = Vec2::splat(size);
+        self
+    }
+}
+
+impl ToggleVisual for SwitchVisual {
+    fn default_config() -> ToggleConfig {
+        ToggleConfig::default()
+    }
+
+    fn spawn_visual(
+        commands: &mut Commands,
+        toggle_entity: Entity,
+        config: &ToggleConfig,
+        state: ToggleState,
+    ) {
+        // Spawn track
+        let track_entity = commands.spawn((
             Node {
                 width: Val::Percent(100.0),
                 height: Val::Percent(100.0),
                 ..default()
             },
             BorderRadius::all(Val::Px(12.0)),
-            BackgroundColor(if self.initial_state {
-                self.config.on_color
-            } else {
-                self.config.off_color
-            }),
+            BackgroundColor(config.off_color.mix(&config.on_color, state.progress())),
             ToggleTrack,
         )).id();
 
         // Spawn knob
-        let knob_x = if self.initial_state {
-            self.config.size.x - self.config.size.y + self.config.knob_margin
-        } else {
-            self.config.knob_margin
-        };
-        
-        let knob_entity = self.commands.spawn((
+        let knob_x = config.knob_margin + (config.size.x - config.size.y) * state.progress();
+
+        let knob_entity = commands.spawn((
             Node {
-                width: Val::Px(self.config.size.y - self.config.knob_margin * 2.0),
-                height: Val::Px(self.config.size.y - self.config.knob_margin * 2.0),
+                width: Val::Px(config.size.y - config.knob_margin * 2.0),
+                height: Val::Px(config.size.y - config.knob_margin * 2.0),
                 position_type: PositionType::Absolute,
                 left: Val::Px(knob_x),
                 ..default()
             },
             BorderRadius::all(Val::Percent(50.0)),
-            BackgroundColor(self.config.knob_color),
+            BackgroundColor(config.knob_color),
             ToggleKnob,
         )).id();
 
-        let toggle_entity = self.commands.spawn((
-            Button,
+        let content_entities = spawn_toggle_content(commands, config, state, track_entity, knob_entity);
+
+        commands.entity(toggle_entity)
+            .insert((
+                Node {
+                    width: Val::Px(config.size.x),
+                    height: Val::Px(config.size.y),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                ToggleParts {
+                    track: track_entity,
+                    knob: knob_entity,
+                    content: content_entities,
+                },
+            ))
+            .add_children(&[track_entity, knob_entity]);
+    }
+}
+
+/// Icons fill this share of the knob's height
+const TOGGLE_CONTENT_SCALE: f32 = 0.7;
+
+/// Spawns `config`'s track and knob content, faded to `state`.
+fn spawn_toggle_content(
+    commands: &mut Commands,
+    config: &ToggleConfig,
+    state: ToggleState,
+    track_entity: Entity,
+    knob_entity: Entity,
+) -> Vec<Entity> {
+    let knob_size = config.size.y - config.knob_margin * 2.0;
+    let content_size = knob_size * TOGGLE_CONTENT_SCALE;
+    // Track content fills the side the knob has left
+    let beside_knob = config.size.x - config.size.y;
+    let mut content_entities = Vec::new();
+
+    let slots = [
+        (&config.track_content.on, true, false, Node {
+            left: Val::Px(0.0),
+            width: Val::Px(beside_knob),
+            height: Val::Percent(100.0),
+            ..default()
+        }),
+        (&config.track_content.off, false, false, Node {
+            right: Val::Px(0.0),
+            width: Val::Px(beside_knob),
+            height: Val::Percent(100.0),
+            ..default()
+        }),
+        (&config.knob_content.on, true, true, Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        }),
+        (&config.knob_content.off, false, true, Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        }),
+    ];
+
+    for (content, shown_when_on, in_knob, slot_node) in slots {
+        let Some(content) = content else {
+            continue;
+        };
+        let progress = state.progress();
+        let alpha = if shown_when_on { progress } else { 1.0 - progress };
+        let fade = ToggleContentFade { shown_when_on, in_knob };
+
+        let content_entity = match content {
+            ToggleContent::Text(text) => commands.spawn((
+                Text::new(text.clone()),
+                TextColor(config.knob_color.with_alpha(alpha)),
+                fade,
+            )).id(),
+            ToggleContent::Icon(icon) => commands.spawn((
+                Node {
+                    width: Val::Px(content_size),
+                    height: Val::Px(content_size),
+                    ..default()
+                },
+                ImageNode::new(icon.clone()).with_color(Color::WHITE.with_alpha(alpha)),
+                fade,
+            )).id(),
+        };
+
+        let slot_entity = commands.spawn(Node {
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..slot_node
+        }).id();
+        commands.entity(slot_entity).add_child(content_entity);
+        commands.entity(if in_knob { knob_entity } else { track_entity }).add_child(slot_entity);
+        content_entities.push(content_entity);
+    }
+
+    content_entities
+}
+
+/// Width of the checkmark and dash strokes, in pixels
+const CHECKBOX_STROKE: f32 = 2.0;
+
+impl ToggleVisual for CheckboxVisual {
+    fn default_config() -> ToggleConfig {
+        ToggleConfig {
+            size: Vec2::splat(18.0),
+            ..default()
+        }
+    }
+
+    fn spawn_visual(
+        commands: &mut Commands,
+        checkbox_entity: Entity,
+        config: &ToggleConfig,
+        state: ToggleState,
+    ) {
+        let glyph_display = |shown: bool| if shown { Display::Flex } else { Display::None };
+
+        // An L of two borders turned 45° clockwise, like the usual CSS checkmark
+        let checkmark_entity = commands.spawn((
             Node {
-                width: Val::Px(self.config.size.x),
-                height: Val::Px(self.config.size.y),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
+                display: glyph_display(state == ToggleState::On),
+                width: Val::Percent(30.0),
+                height: Val::Percent(60.0),
+                margin: UiRect::bottom(Val::Percent(15.0)),
+                border: UiRect {
+                    right: Val::Px(CHECKBOX_STROKE),
+                    bottom: Val::Px(CHECKBOX_STROKE),
+                    ..default()
+                },
                 ..default()
             },
-            BackgroundColor(Color::NONE),
-            Toggle {
-                is_on: self.initial_state,
-            },
-            self.config,
-            ToggleAnimation {
-                progress: if self.initial_state { 1.0 } else { 0.0 },
-                target_progress: if self.initial_state { 1.0 } else { 0.0 },
+            BorderColor(config.knob_color),
+            Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            CheckboxCheckmark,
+        )).id();
+
+        let dash_entity = commands.spawn((
+            Node {
+                display: glyph_display(state == ToggleState::Mixed),
+                width: Val::Percent(60.0),
+                height: Val::Px(CHECKBOX_STROKE),
                 ..default()
             },
-            ToggleParts {
-                track: track_entity,
-                knob: knob_entity,
-            },
-            ToggleNeedsVisualUpdate,
-            StateScoped(state), // Add StateScoped here
+            BackgroundColor(config.knob_color),
+            CheckboxDash,
         )).id();
 
-        if self.disabled {
-            self.commands.entity(toggle_entity).insert(ToggleDisabled);
-        }
-
-        self.commands.entity(toggle_entity)
-            .add_children(&[track_entity, knob_entity]);
-            
-        #[cfg(debug_assertions)]
-        info!("State-scoped toggle widget created with entity: {:?}", toggle_entity);
-
-        toggle_entity
+        commands.entity(checkbox_entity)
+            .insert((
+                Node {
+                    width: Val::Px(config.size.x),
+                    height: Val::Px(config.size.y),
+                    border: UiRect::all(Val::Px(CHECKBOX_STROKE)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(if state == ToggleState::Off {
+                    config.off_color
+                } else {
+                    config.on_color
+                }),
+                BorderColor::default(),
+                BorderRadius::default(),
+                Checkbox,
+                CheckboxParts {
+                    checkmark: checkmark_entity,
+                    dash: dash_entity,
+                },
+            ))
+            .add_children(&[checkmark_entity, dash_entity]);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use crate::binding::BindableWidget;
+use super::{components::*, events::*};
+
+/// Drive a toggle from code. Changes are tagged `ToggleChangeKind::Programmatic`;
+/// the `_silently` variants skip the event, e.g. when restoring saved state.
+/// Both take a `bool` or a `ToggleState`, so code can set `Mixed` whatever the
+/// toggle's `ToggleCycle`.
+pub trait ToggleCommandsExt {
+    fn set_toggle(&mut self, state: impl Into<ToggleState>) -> &mut Self;
+    fn set_toggle_silently(&mut self, state: impl Into<ToggleState>) -> &mut Self;
+}
+
+impl ToggleCommandsExt for EntityCommands<'_> {
+    fn set_toggle(&mut self, state: impl Into<ToggleState>) -> &mut Self {
+        self.queue(set_toggle(state.into(), true))
+    }
+
+    fn set_toggle_silently(&mut self, state: impl Into<ToggleState>) -> &mut Self {
+        self.queue(set_toggle(state.into(), false))
+    }
+}
+
+fn set_toggle(state: ToggleState, notify: bool) -> impl FnOnce(EntityWorldMut) + Send + 'static {
+    move |mut entity: EntityWorldMut| {
+        let Some(mut toggle) = entity.get_mut::<Toggle>() else {
+            return;
+        };
+        let previous_state = toggle.state;
+        if previous_state == state {
+            return;
+        }
+        toggle.state = state;
+        entity.insert(ToggleNeedsVisualUpdate);
+
+        if notify {
+            let toggle_entity = entity.id();
+            entity.world_scope(|world| {
+                world.send_event(ToggleChangedEvent {
+                    toggle_entity,
+                    previous_state,
+                    new_state: state,
+                    kind: ToggleChangeKind::Programmatic,
+                });
+            });
+        }
+    }
+}
+
+/// Binds as a `bool`; a mixed toggle reads as off.
+impl BindableWidget for Toggle {
+    type Value = bool;
+
+    fn bound_value(&self) -> bool {
+        self.is_on()
+    }
+
+    fn apply_bound_value(entity: EntityWorldMut, value: bool) {
+        set_toggle(value.into(), false)(entity);
+    }
+}
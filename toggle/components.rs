@@ -1,65 +1,258 @@
 use bevy::prelude::*;
+use crate::anim::{AnimatedValue, EasingFunction, Transition};
+use crate::theme::ExplicitColors;
 
 #[derive(Component, Debug, Clone, Reflect)]
 pub struct Toggle {
-    pub is_on: bool,
+    pub state: ToggleState,
+}
+
+impl Toggle {
+    pub fn is_on(&self) -> bool {
+        self.state.is_on()
+    }
+}
+
+/// Checked state of a toggle or checkbox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum ToggleState {
+    #[default]
+    Off,
+    On,
+    /// Partly on, e.g. a "select all" checkbox over a partial selection
+    Mixed,
+}
+
+impl ToggleState {
+    pub fn is_on(self) -> bool {
+        self == ToggleState::On
+    }
+
+    /// The state a click or Space/Enter moves to.
+    pub fn next(self, cycle: ToggleCycle) -> Self {
+        match (self, cycle) {
+            (ToggleState::Off, _) => ToggleState::On,
+            (ToggleState::On, ToggleCycle::TwoState) => ToggleState::Off,
+            (ToggleState::On, ToggleCycle::TriState) => ToggleState::Mixed,
+            (ToggleState::Mixed, ToggleCycle::TwoState) => ToggleState::On,
+            (ToggleState::Mixed, ToggleCycle::TriState) => ToggleState::Off,
+        }
+    }
+
+    /// Where a switch's knob rests: 0 off, 1 on and halfway when mixed.
+    pub(crate) fn progress(self) -> f32 {
+        match self {
+            ToggleState::Off => 0.0,
+            ToggleState::On => 1.0,
+            ToggleState::Mixed => 0.5,
+        }
+    }
+}
+
+impl From<bool> for ToggleState {
+    fn from(is_on: bool) -> Self {
+        if is_on { ToggleState::On } else { ToggleState::Off }
+    }
+}
+
+/// Which states clicks and key presses step through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum ToggleCycle {
+    /// Off and On swap. Mixed can only be set from code and turns On when
+    /// clicked, like a "select all" checkbox.
+    #[default]
+    TwoState,
+    /// Off → On → Mixed → Off, so users can pick the mixed state too.
+    TriState,
 }
 
 #[derive(Component, Debug, Clone, Reflect)]
 pub struct ToggleParts {
     pub track: Entity,
     pub knob: Entity,
+    /// Text and icons spawned from `ToggleConfig::track_content` and
+    /// `knob_content`
+    pub content: Vec<Entity>,
+}
+
+/// Text or an icon drawn inside a switch.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub enum ToggleContent {
+    Text(String),
+    Icon(Handle<Image>),
+}
+
+impl From<&str> for ToggleContent {
+    fn from(text: &str) -> Self {
+        ToggleContent::Text(text.to_string())
+    }
+}
+
+impl From<String> for ToggleContent {
+    fn from(text: String) -> Self {
+        ToggleContent::Text(text)
+    }
+}
+
+impl From<Handle<Image>> for ToggleContent {
+    fn from(icon: Handle<Image>) -> Self {
+        ToggleContent::Icon(icon)
+    }
+}
+
+/// What a switch shows when on and when off. The two cross-fade with
+/// `ToggleAnimation::progress`; leave one `None` to fade the other in and out.
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+pub struct ToggleStateContent {
+    pub on: Option<ToggleContent>,
+    pub off: Option<ToggleContent>,
+}
+
+impl ToggleStateContent {
+    pub fn new(on: impl Into<ToggleContent>, off: impl Into<ToggleContent>) -> Self {
+        Self {
+            on: Some(on.into()),
+            off: Some(off.into()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.on.is_none() && self.off.is_none()
+    }
 }
 
 #[derive(Component, Debug, Clone, Reflect)]
 pub struct ToggleConfig {
     pub animated: bool,
+    /// How the knob slides between off and on
+    pub knob_transition: Transition,
+    /// How the track color and content fade between off and on
+    pub color_transition: Transition,
+    /// Seconds. When changed from 0.2, both transitions become ease-out
+    /// tweens of this length.
+    #[deprecated(note = "use `knob_transition` and `color_transition`")]
     pub animation_duration: f32,
+    pub cycle: ToggleCycle,
     pub on_color: Color,
     pub off_color: Color,
     pub knob_color: Color,
     pub size: Vec2,
     pub knob_margin: f32,
     pub drag_threshold: f32,
+    /// Shown in a switch's track beside the knob, e.g. "ON"/"OFF". Text is
+    /// drawn in `knob_color`. Checkboxes ignore this and `knob_content`.
+    pub track_content: ToggleStateContent,
+    /// Shown in the knob, e.g. a sun and a moon icon. Text is drawn in the
+    /// track color.
+    pub knob_content: ToggleStateContent,
 }
 
 impl Default for ToggleConfig {
+    #[allow(deprecated)]
     fn default() -> Self {
         Self {
             animated: true,
-            animation_duration: 0.2,
+            knob_transition: Transition::default(),
+            color_transition: Transition::default(),
+            animation_duration: DEFAULT_ANIMATION_DURATION,
+            cycle: ToggleCycle::TwoState,
             on_color: Color::srgb(0.2, 0.8, 0.2),
             off_color: Color::srgb(0.5, 0.5, 0.5),
             knob_color: Color::WHITE,
             size: Vec2::new(50.0, 25.0),
             knob_margin: 2.0,
             drag_threshold: 5.0,
+            track_content: ToggleStateContent::default(),
+            knob_content: ToggleStateContent::default(),
         }
     }
 }
 
+/// `ToggleConfig::animation_duration` before it was replaced.
+const DEFAULT_ANIMATION_DURATION: f32 = 0.2;
+
+impl ToggleConfig {
+    /// The knob and color transitions, with a changed `animation_duration`
+    /// taking over both.
+    #[allow(deprecated)]
+    pub(crate) fn transitions(&self) -> (Transition, Transition) {
+        if self.animation_duration != DEFAULT_ANIMATION_DURATION {
+            let legacy = Transition::tween(self.animation_duration, EasingFunction::EaseOut);
+            return (legacy, legacy);
+        }
+        (self.knob_transition, self.color_transition)
+    }
+
+    /// The on, off and knob colors that differ from the defaults, in that order.
+    pub(crate) fn explicit_colors(&self) -> ExplicitColors {
+        let default = Self::default();
+        ExplicitColors::from_defaults(&[
+            (self.on_color, default.on_color),
+            (self.off_color, default.off_color),
+            (self.knob_color, default.knob_color),
+        ])
+    }
+}
+
 #[derive(Component)]
 pub struct ToggleTrack;
 
 #[derive(Component)]
 pub struct ToggleKnob;
 
+/// A piece of `ToggleConfig::track_content` or `knob_content`, faded in as
+/// the toggle moves towards the state it belongs to.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+pub struct ToggleContentFade {
+    pub shown_when_on: bool,
+    pub in_knob: bool,
+}
+
+/// Draws a toggle as a checkbox instead of a switch. `ToggleConfig::on_color`
+/// fills the box when checked or mixed, `off_color` when unchecked, and
+/// `knob_color` draws the glyph.
+#[derive(Component, Debug, Default)]
+pub struct Checkbox;
+
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct CheckboxParts {
+    pub checkmark: Entity,
+    pub dash: Entity,
+}
+
+/// Shown when a checkbox is on.
 #[derive(Component)]
-pub struct ToggleNeedsVisualUpdate;
+pub struct CheckboxCheckmark;
 
+/// Shown when a checkbox is mixed.
 #[derive(Component)]
-pub struct ToggleFocused;
+pub struct CheckboxDash;
+
+#[derive(Component)]
+pub struct ToggleNeedsVisualUpdate;
 
 #[derive(Component)]
 pub struct ToggleDisabled;
 
 #[derive(Component, Default)]
 pub struct ToggleAnimation {
-    pub progress: f32,
-    pub target_progress: f32,
-    pub velocity: f32,
+    /// Knob position: 0 when off, 1 when on, halfway when mixed
+    pub progress: AnimatedValue<f32>,
+    /// Track color and content fade, on the same scale as `progress`
+    pub color: AnimatedValue<f32>,
+}
+
+impl ToggleAnimation {
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: AnimatedValue::new(progress),
+            color: AnimatedValue::new(progress),
+        }
+    }
 }
 
+/// Present while the toggle is pressed. The press becomes a drag once the
+/// cursor moves `ToggleConfig::drag_threshold` pixels along the track.
 #[derive(Component, Default)]
 pub struct ToggleDragState {
     pub is_dragging: bool,
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use super::components::ToggleState;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ToggleChangeKind {
@@ -9,7 +10,7 @@ pub enum ToggleChangeKind {
 #[derive(Event, Debug, Clone, Copy)]
 pub struct ToggleChangedEvent {
     pub toggle_entity: Entity,
-    pub previous_state: bool,
-    pub new_state: bool,
+    pub previous_state: ToggleState,
+    pub new_state: ToggleState,
     pub kind: ToggleChangeKind,
 }
\ No newline at end of file
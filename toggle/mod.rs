@@ -1,19 +1,35 @@
 use bevy::prelude::*;
+use crate::binding::{WidgetBindingPlugin, WidgetBindingSystem};
+use crate::focus::{WidgetFocusPlugin, WidgetFocusSystem};
+use crate::input::WidgetInputPlugin;
+use crate::label::WidgetLabelPlugin;
+use crate::motion::WidgetMotionPlugin;
+use crate::theme::WidgetThemePlugin;
+use crate::WidgetAppExt;
 
 pub mod components;
 pub mod events;
 pub mod systems;
 pub mod builder;
+pub mod commands;
 
 pub use components::*;
 pub use events::*;
 pub use systems::*;
 pub use builder::*;
+pub use commands::ToggleCommandsExt;
 
 pub struct TogglePlugin;
 
 impl Plugin for TogglePlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugin_once(WidgetInputPlugin);
+        app.add_plugin_once(WidgetFocusPlugin);
+        app.add_plugin_once(WidgetThemePlugin);
+        app.add_plugin_once(WidgetMotionPlugin);
+        app.add_plugin_once(WidgetBindingPlugin);
+        app.add_plugin_once(WidgetLabelPlugin);
+
         app
             .add_event::<ToggleChangedEvent>()
             .configure_sets(
@@ -21,18 +37,33 @@ impl Plugin for TogglePlugin {
                 (
                     ToggleSystem::ProcessInput
                         .after(bevy::input::InputSystem)
+                        .after(WidgetFocusSystem)
                         .before(bevy::ui::UiSystem::Layout),
                     ToggleSystem::UpdateAnimation.after(ToggleSystem::ProcessInput),
                     ToggleSystem::UpdateVisuals.after(ToggleSystem::UpdateAnimation),
                 )
                 .chain(),
             )
+            .configure_sets(
+                Update,
+                WidgetBindingSystem.after(ToggleSystem::ProcessInput).before(ToggleSystem::UpdateVisuals),
+            )
             .add_systems(Update, (
-                toggle_interaction_system.in_set(ToggleSystem::ProcessInput),
+                (toggle_interaction_system, toggle_drag_system)
+                    .chain()
+                    .in_set(ToggleSystem::ProcessInput),
                 toggle_keyboard_system.in_set(ToggleSystem::ProcessInput),
-                toggle_focus_system.in_set(ToggleSystem::ProcessInput),
+                toggle_label_system.in_set(ToggleSystem::ProcessInput),
                 toggle_animation_system.in_set(ToggleSystem::UpdateAnimation),
+                toggle_theme_system
+                    .in_set(ToggleSystem::UpdateVisuals)
+                    .before(toggle_visual_update_system),
                 toggle_visual_update_system.in_set(ToggleSystem::UpdateVisuals),
+                checkbox_theme_system
+                    .in_set(ToggleSystem::UpdateVisuals)
+                    .before(checkbox_visual_update_system),
+                checkbox_visual_update_system.in_set(ToggleSystem::UpdateVisuals),
+                toggle_accessibility_system.in_set(ToggleSystem::UpdateVisuals),
             ));
         
         #[cfg(debug_assertions)]
@@ -1,5 +1,16 @@
+use accesskit::{Role, Toggled};
+use bevy::a11y::AccessibilityNode;
+use bevy::ecs::entity::EntityHashSet;
 use bevy::prelude::*;
+use bevy::ui::UiScale;
 use super::{components::*, events::*};
+use crate::accessibility::{needs_accessibility_sync, set_disabled, set_label_links, sync_accessibility_node};
+use crate::anim::AnimatedValue;
+use crate::focus::WidgetFocused;
+use crate::input::WidgetCursor;
+use crate::label::{WidgetLabel, WidgetLabels};
+use crate::motion::WidgetMotionSettings;
+use crate::theme::{ExplicitColors, ThemeLookup};
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum ToggleSystem {
@@ -8,43 +19,108 @@ pub enum ToggleSystem {
     UpdateVisuals,
 }
 
+/// Starts a press gesture. Whether it ends as a click or a drag is decided by
+/// `toggle_drag_system`.
 pub fn toggle_interaction_system(
     mut commands: Commands,
-    mut q_toggles: Query<(Entity, &Interaction, &mut Toggle), (Changed<Interaction>, Without<ToggleDisabled>)>,
-    mut evw_toggle_change: EventWriter<ToggleChangedEvent>,
+    q_toggles: Query<(Entity, &Interaction, &ToggleAnimation), (Changed<Interaction>, Without<ToggleDisabled>)>,
+    cursor: Res<WidgetCursor>,
 ) {
-    for (entity, interaction, mut toggle) in &mut q_toggles {
+    for (entity, interaction, animation) in &q_toggles {
         if let Interaction::Pressed = interaction {
-            let previous_state = toggle.is_on;
-            toggle.is_on = !toggle.is_on;
+            commands.entity(entity).insert(ToggleDragState {
+                is_dragging: false,
+                drag_start_position: cursor.position.unwrap_or_default(),
+                drag_start_progress: animation.progress.value(),
+            });
+        }
+    }
+}
+
+/// Moves the knob with the cursor once a press travels past
+/// `ToggleConfig::drag_threshold`. On release a drag settles on the nearer
+/// side and a shorter press moves to the next state like a click.
+pub fn toggle_drag_system(
+    mut commands: Commands,
+    mut q_toggles: Query<(
+        Entity,
+        &Interaction,
+        &mut ToggleDragState,
+        &mut Toggle,
+        &mut ToggleAnimation,
+        &ToggleConfig,
+        Has<ToggleDisabled>,
+    )>,
+    mut evw_toggle_change: EventWriter<ToggleChangedEvent>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    ui_scale: Res<UiScale>,
+    cursor: Res<WidgetCursor>,
+) {
+    for (entity, interaction, mut drag, mut toggle, mut animation, config, disabled) in &mut q_toggles {
+        if disabled {
+            commands.entity(entity).remove::<ToggleDragState>();
+            continue;
+        }
+        
+        let held = *interaction == Interaction::Pressed && mouse_input.pressed(MouseButton::Left);
+        if held {
+            let Some(cursor_pos) = cursor.position else {
+                continue;
+            };
+            // The knob travels the track width minus its own size, so a
+            // checkbox has nowhere to drag
+            let travel = (config.size.x - config.size.y) * ui_scale.0;
+            let offset = cursor_pos.x - drag.drag_start_position.x;
+            if !drag.is_dragging && travel > 0.0 && offset.abs() >= config.drag_threshold {
+                drag.is_dragging = true;
+            }
+            
+            if drag.is_dragging {
+                let progress = (drag.drag_start_progress + offset / travel).clamp(0.0, 1.0);
+                animation.progress.jump_to(progress);
+                animation.color.jump_to(progress);
+                commands.entity(entity).insert(ToggleNeedsVisualUpdate);
+            }
+            continue;
+        }
+        
+        commands.entity(entity).remove::<ToggleDragState>();
+        let new_state = if drag.is_dragging {
+            ToggleState::from(animation.progress.value() >= 0.5)
+        } else {
+            toggle.state.next(config.cycle)
+        };
+        if new_state != toggle.state {
+            let previous_state = toggle.state;
+            toggle.state = new_state;
             
             evw_toggle_change.write(ToggleChangedEvent {
                 toggle_entity: entity,
                 previous_state,
-                new_state: toggle.is_on,
+                new_state,
                 kind: ToggleChangeKind::User,
             });
-            
-            commands.entity(entity).insert(ToggleNeedsVisualUpdate);
         }
+        
+        commands.entity(entity).insert(ToggleNeedsVisualUpdate);
     }
 }
 
 pub fn toggle_keyboard_system(
     mut commands: Commands,
-    mut q_toggles: Query<(Entity, &mut Toggle), With<ToggleFocused>>,
+    mut q_toggles: Query<(Entity, &mut Toggle, &ToggleConfig), (With<WidgetFocused>, Without<ToggleDisabled>)>,
     keys: Res<ButtonInput<KeyCode>>,
     mut evw_toggle_change: EventWriter<ToggleChangedEvent>,
 ) {
-    for (entity, mut toggle) in &mut q_toggles {
+    for (entity, mut toggle, config) in &mut q_toggles {
         if keys.just_pressed(KeyCode::Space) || keys.just_pressed(KeyCode::Enter) {
-            let previous_state = toggle.is_on;
-            toggle.is_on = !toggle.is_on;
+            let previous_state = toggle.state;
+            toggle.state = toggle.state.next(config.cycle);
             
             evw_toggle_change.write(ToggleChangedEvent {
                 toggle_entity: entity,
                 previous_state,
-                new_state: toggle.is_on,
+                new_state: toggle.state,
                 kind: ToggleChangeKind::User,
             });
             
@@ -53,29 +129,82 @@ pub fn toggle_keyboard_system(
     }
 }
 
+/// Releasing a press on a toggle's label moves it to its next state, like a
+/// click. Dragging off the label before releasing cancels.
+pub fn toggle_label_system(
+    mut commands: Commands,
+    q_labels: Query<(Entity, &Interaction, &WidgetLabel), Changed<Interaction>>,
+    mut q_toggles: Query<(&mut Toggle, &ToggleConfig), Without<ToggleDisabled>>,
+    mut evw_toggle_change: EventWriter<ToggleChangedEvent>,
+    mut pressed_labels: Local<EntityHashSet>,
+) {
+    for (label_entity, interaction, label) in &q_labels {
+        match interaction {
+            Interaction::Pressed => {
+                pressed_labels.insert(label_entity);
+                continue;
+            }
+            Interaction::None => {
+                pressed_labels.remove(&label_entity);
+                continue;
+            }
+            Interaction::Hovered => {
+                if !pressed_labels.remove(&label_entity) {
+                    continue;
+                }
+            }
+        }
+        let Ok((mut toggle, config)) = q_toggles.get_mut(label.control) else {
+            continue;
+        };
+        let previous_state = toggle.state;
+        toggle.state = toggle.state.next(config.cycle);
+
+        evw_toggle_change.write(ToggleChangedEvent {
+            toggle_entity: label.control,
+            previous_state,
+            new_state: toggle.state,
+            kind: ToggleChangeKind::User,
+        });
+
+        commands.entity(label.control).insert(ToggleNeedsVisualUpdate);
+    }
+}
+
 pub fn toggle_animation_system(
+    mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(&mut ToggleAnimation, &ToggleConfig, &Toggle)>,
+    motion: Res<WidgetMotionSettings>,
+    mut query: Query<(Entity, &mut ToggleAnimation, &ToggleConfig, &Toggle, Option<&ToggleDragState>)>,
 ) {
-    for (mut animation, config, toggle) in &mut query {
-        if !config.animated {
-            animation.progress = if toggle.is_on { 1.0 } else { 0.0 };
-            animation.target_progress = animation.progress;
+    for (entity, mut animation, config, toggle, drag) in &mut query {
+        // The cursor owns the knob mid-drag
+        if drag.is_some_and(|drag| drag.is_dragging) {
             continue;
         }
         
-        let target = if toggle.is_on { 1.0 } else { 0.0 };
-        animation.target_progress = target;
-        
-        // Simple lerp for toggle (spring might be overkill)
-        let speed = 1.0 / config.animation_duration.max(0.001);
-        let delta = (animation.target_progress - animation.progress) * speed * time.delta_secs();
-        animation.progress += delta;
+        let target = toggle.state.progress();
+        let settled = |value: &AnimatedValue<f32>| value.is_settled() && value.target() == target;
+        if settled(&animation.progress) && settled(&animation.color) {
+            continue;
+        }
         
-        // Snap to target when close
-        if (animation.target_progress - animation.progress).abs() < 0.01 {
-            animation.progress = animation.target_progress;
+        let animation = &mut *animation;
+        let (knob_transition, color_transition) = config.transitions();
+        for (value, transition) in [
+            (&mut animation.progress, knob_transition),
+            (&mut animation.color, color_transition),
+        ] {
+            value.set_target(target);
+            if config.animated {
+                value.update(&transition, &motion, &time);
+            } else {
+                value.jump_to(target);
+            }
         }
+        
+        // The knob follows the animation every frame until it settles
+        commands.entity(entity).insert(ToggleNeedsVisualUpdate);
     }
 }
 
@@ -91,15 +220,16 @@ pub fn toggle_visual_update_system(
     ), With<ToggleNeedsVisualUpdate>>,
     mut q_tracks: Query<&mut BackgroundColor, With<ToggleTrack>>,
     mut q_knobs: Query<&mut Node, With<ToggleKnob>>,
+    mut q_content: Query<(&ToggleContentFade, Option<&mut TextColor>, Option<&mut ImageNode>)>,
 ) {
     for (entity, toggle, animation, config, parts, disabled) in &mut q_toggles {
         // Update track color
         if let Ok(mut track_color) = q_tracks.get_mut(parts.track) {
-            let target_color = if toggle.is_on { config.on_color } else { config.off_color };
+            let target_color = config.off_color.mix(&config.on_color, toggle.state.progress());
             if config.animated {
                 *track_color = BackgroundColor(config.off_color.mix(
                     &config.on_color, 
-                    animation.progress
+                    animation.color.value()
                 ));
             } else {
                 *track_color = BackgroundColor(target_color);
@@ -114,44 +244,172 @@ pub fn toggle_visual_update_system(
         if let Ok(mut knob_style) = q_knobs.get_mut(parts.knob) {
             let knob_size = config.size.y - config.knob_margin * 2.0;
             let travel_distance = config.size.x - config.size.y;
-            let x_position = config.knob_margin + (travel_distance * animation.progress);
+            let x_position = config.knob_margin + (travel_distance * animation.progress.value());
             
             knob_style.left = Val::Px(x_position);
             knob_style.width = Val::Px(knob_size);
             knob_style.height = Val::Px(knob_size);
         }
+
+        // Cross-fade the on and off content
+        let progress = animation.color.value();
+        let dim = if disabled.is_some() { 0.5 } else { 1.0 };
+        let mut iter = q_content.iter_many_mut(&parts.content);
+        while let Some((fade, text_color, image)) = iter.fetch_next() {
+            let alpha = if fade.shown_when_on { progress } else { 1.0 - progress } * dim;
+            if let Some(mut text_color) = text_color {
+                let color = if fade.in_knob {
+                    config.off_color.mix(&config.on_color, progress)
+                } else {
+                    config.knob_color
+                };
+                text_color.0 = color.with_alpha(alpha);
+            }
+            if let Some(mut image) = image {
+                image.color = Color::WHITE.with_alpha(alpha);
+            }
+        }
         
         commands.entity(entity).remove::<ToggleNeedsVisualUpdate>();
     }
 }
 
-pub fn toggle_focus_system(
+/// Copies theme colors the builder wasn't given into each toggle's config
+/// and sets the font of its track and knob text, on spawn and whenever the
+/// theme changes.
+pub fn toggle_theme_system(
     mut commands: Commands,
-    q_toggles: Query<(Entity, &Interaction), (With<Toggle>, Changed<Interaction>)>,
+    themes: ThemeLookup,
+    mut q_toggles: Query<(Entity, Ref<Toggle>, &mut ToggleConfig, &ToggleParts, Option<&ExplicitColors>)>,
+    mut q_tracks: Query<&mut BorderRadius, With<ToggleTrack>>,
+    mut q_knobs: Query<&mut BackgroundColor, With<ToggleKnob>>,
+    mut q_content_text: Query<&mut TextFont, With<ToggleContentFade>>,
 ) {
-    for (entity, interaction) in &q_toggles {
-        match interaction {
-            Interaction::Pressed => {
-                commands.entity(entity).insert(ToggleFocused);
-            }
-            Interaction::None => {
-                commands.entity(entity).remove::<ToggleFocused>();
-            }
-            _ => {}
+    let restyle_all = themes.changed();
+
+    for (entity, toggle, mut config, parts, explicit) in &mut q_toggles {
+        if !restyle_all && !toggle.is_added() {
+            continue;
+        }
+        let theme = themes.get(entity);
+        let explicit = explicit.copied().unwrap_or_default();
+        explicit.apply(0, &mut config.on_color, theme.palette.success);
+        explicit.apply(1, &mut config.off_color, theme.palette.track);
+        explicit.apply(2, &mut config.knob_color, theme.palette.handle);
+
+        if let Ok(mut radius) = q_tracks.get_mut(parts.track) {
+            *radius = BorderRadius::all(Val::Px(theme.radii.pill));
         }
+        if let Ok(mut knob_color) = q_knobs.get_mut(parts.knob) {
+            knob_color.0 = config.knob_color;
+        }
+        let mut content_text = q_content_text.iter_many_mut(&parts.content);
+        while let Some(mut font) = content_text.fetch_next() {
+            *font = theme.typography.text_font(theme.typography.label_size);
+        }
+
+        commands.entity(entity).insert(ToggleNeedsVisualUpdate);
+    }
+}
+
+/// Fills the box and shows the glyph for each checkbox's state.
+pub fn checkbox_visual_update_system(
+    mut commands: Commands,
+    mut q_checkboxes: Query<
+        (Entity, &Toggle, &ToggleConfig, &CheckboxParts, Has<ToggleDisabled>, &mut BackgroundColor),
+        (With<Checkbox>, With<ToggleNeedsVisualUpdate>)
+    >,
+    mut q_checkmarks: Query<(&mut Node, &mut BorderColor), (With<CheckboxCheckmark>, Without<CheckboxDash>)>,
+    mut q_dashes: Query<(&mut Node, &mut BackgroundColor), (With<CheckboxDash>, Without<Checkbox>)>,
+) {
+    let display = |shown: bool| if shown { Display::Flex } else { Display::None };
+
+    for (entity, toggle, config, parts, disabled, mut fill) in &mut q_checkboxes {
+        fill.0 = if toggle.state == ToggleState::Off { config.off_color } else { config.on_color };
+        if disabled {
+            fill.0 = fill.0.with_alpha(0.5);
+        }
+
+        if let Ok((mut node, mut color)) = q_checkmarks.get_mut(parts.checkmark) {
+            node.display = display(toggle.state == ToggleState::On);
+            color.0 = config.knob_color;
+        }
+        if let Ok((mut node, mut color)) = q_dashes.get_mut(parts.dash) {
+            node.display = display(toggle.state == ToggleState::Mixed);
+            color.0 = config.knob_color;
+        }
+
+        commands.entity(entity).remove::<ToggleNeedsVisualUpdate>();
+    }
+}
+
+/// Copies theme colors the builder wasn't given into each checkbox's config
+/// and styles its box, on spawn and whenever the theme changes.
+pub fn checkbox_theme_system(
+    mut commands: Commands,
+    themes: ThemeLookup,
+    mut q_checkboxes: Query<
+        (Entity, Ref<Toggle>, &mut ToggleConfig, Option<&ExplicitColors>, &mut BorderColor, &mut BorderRadius),
+        With<Checkbox>
+    >,
+) {
+    let restyle_all = themes.changed();
+
+    for (entity, toggle, mut config, explicit, mut border, mut radius) in &mut q_checkboxes {
+        if !restyle_all && !toggle.is_added() {
+            continue;
+        }
+        let theme = themes.get(entity);
+        let explicit = explicit.copied().unwrap_or_default();
+        explicit.apply(0, &mut config.on_color, theme.palette.accent);
+        explicit.apply(1, &mut config.off_color, theme.palette.surface);
+        explicit.apply(2, &mut config.knob_color, theme.palette.handle);
+        border.0 = theme.palette.border;
+        *radius = BorderRadius::all(Val::Px(theme.radii.control));
+
+        commands.entity(entity).insert(ToggleNeedsVisualUpdate);
+    }
+}
+
+/// Publishes each toggle as a switch, or checkbox, with its checked state
+/// and links to its label and description.
+pub fn toggle_accessibility_system(
+    mut commands: Commands,
+    mut q_toggles: Query<(
+        Entity,
+        Ref<Toggle>,
+        Has<Checkbox>,
+        Option<Ref<ToggleDisabled>>,
+        Option<&WidgetLabels>,
+        Option<&mut AccessibilityNode>,
+    )>,
+    mut removed_disabled: RemovedComponents<ToggleDisabled>,
+) {
+    let enabled: EntityHashSet = removed_disabled.read().collect();
+
+    for (entity, toggle, checkbox, disabled, labels, node) in &mut q_toggles {
+        let disabled_changed = disabled.as_ref().is_some_and(Ref::is_added) || enabled.contains(&entity);
+        if !needs_accessibility_sync(toggle.is_changed() || disabled_changed, &node) {
+            continue;
+        }
+        let role = if checkbox { Role::CheckBox } else { Role::Switch };
+        sync_accessibility_node(&mut commands, entity, node, role, |node| {
+            node.set_toggled(match toggle.state {
+                ToggleState::Off => Toggled::False,
+                ToggleState::On => Toggled::True,
+                ToggleState::Mixed => Toggled::Mixed,
+            });
+            set_disabled(node, disabled.is_some());
+            set_label_links(node, labels);
+        });
     }
 }
 
 #[cfg(debug_assertions)]
 pub fn debug_toggle_lifecycle_system(
     mut removed_toggles: RemovedComponents<Toggle>,
-    mut removed_state_scoped: RemovedComponents<StateScoped<crate::AppState>>,
 ) {
     for entity in removed_toggles.read() {
         info!("Toggle widget destroyed: entity {:?}", entity);
     }
-    
-    for entity in removed_state_scoped.read() {
-        info!("State-scoped toggle widget destroyed due to state change: entity {:?}", entity);
-    }
 }
\ No newline at end of file